@@ -1,11 +1,19 @@
-//! A cryptographically secure PRNG (CSPRNG) based on [ChaCha8](https://cr.yp.to/chacha.html).
+//! Cryptographically secure PRNGs (CSPRNG) based on [ChaCha](https://cr.yp.to/chacha.html).
+//!
+//! [`ChaChaRng`] uses 8 rounds, which is the recommended trade-off between
+//! throughput and security. [`ChaCha12Rng`] and [`ChaCha20Rng`] use 12 and 20
+//! rounds respectively, and share the same seed format.
 use crate::{
-    source::chacha::{utils::AlignedSeed, ChaCha8},
-    ForkableCore, GenCore, SecureCore, SeededCore, TurboCore, TurboKind,
+    source::chacha::{utils::AlignedSeed, ChaCha12, ChaCha20, ChaCha8, ChaChaState},
+    Error, ForkableCore, GenCore, LabelledForkCore, SecureCore, SeededCore, StreamableCore,
+    TurboCore, TurboKind,
 };
 
 #[cfg(feature = "std")]
-use crate::{entropy::generate_entropy, Rc};
+use crate::entropy::{restoring, LocalRng};
+
+#[cfg(feature = "atomic")]
+use crate::internal::lock::SpinLock;
 
 #[cfg(feature = "fmt")]
 use crate::Debug;
@@ -13,84 +21,608 @@ use crate::Debug;
 #[cfg(feature = "serialize")]
 use crate::{Deserialize, Serialize};
 
-/// A Random Number generator, powered by the `ChaCha8` algorithm.
+macro_rules! chacha_rng {
+    ($(#[$meta:meta])* $name:ident, $source:ty, $snapshot:ident, $local:ident) => {
+        #[doc = concat!("An opaque copy of the full state of a [`", stringify!($name), "`], including")]
+        /// its buffered keystream, for cheaply checkpointing the generator and
+        /// rolling it back later.
+        ///
+        /// As snapshots are [`Copy`], they are not wiped from memory when
+        /// dropped, even with the `zeroize` feature enabled, and should be
+        /// treated as secret key material.
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+        pub struct $snapshot(ChaChaState);
+
+        #[cfg(feature = "fmt")]
+        impl Debug for $snapshot {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($snapshot)).finish()
+            }
+        }
+
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "fmt", derive(Debug))]
+        #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+        #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+        #[repr(transparent)]
+        pub struct $name($source);
+
+        impl $name {
+            /// Returns the current stream selector. Streams with the same seed
+            /// but different selectors yield independent keystreams.
+            #[inline]
+            #[must_use]
+            pub fn get_stream(&self) -> u64 {
+                self.0.get_stream()
+            }
+
+            /// Sets the stream selector, which is the last 8 bytes of the seed.
+            /// The word position in the keystream is preserved, so only the
+            /// stream being read from is changed.
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if fast key erasure is enabled, see [`", stringify!($name), "::set_key_erasure`].")]
+            #[inline]
+            pub fn set_stream(&self, stream: u64) {
+                if self.try_set_stream(stream).is_err() {
+                    panic!("cannot seek while fast key erasure is enabled");
+                }
+            }
+
+            /// Sets the stream selector, which is the last 8 bytes of the seed.
+            /// The word position in the keystream is preserved, so only the
+            /// stream being read from is changed.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::KeyErasureEnabled`] without changing the
+            #[doc = concat!("generator if fast key erasure is enabled, see [`", stringify!($name), "::set_key_erasure`].")]
+            #[inline]
+            pub fn try_set_stream(&self, stream: u64) -> Result<(), Error> {
+                self.0.try_set_stream(stream)
+            }
+
+            /// Returns the position of the next `u32` word to be read from the
+            /// keystream. If a word has only been partially consumed, it is
+            /// counted as consumed.
+            #[inline]
+            #[must_use]
+            pub fn get_word_pos(&self) -> u128 {
+                self.0.get_word_pos()
+            }
+
+            /// Seeks to the given `u32` word position in the keystream, allowing
+            /// random access to any part of the output. Only the lower 68 bits
+            /// of the position are used.
+            ///
+            /// # Panics
+            ///
+            #[doc = concat!("Panics if fast key erasure is enabled, see [`", stringify!($name), "::set_key_erasure`].")]
+            ///
+            /// # Example
+            /// ```
+            /// use turborand::prelude::*;
+            ///
+            #[doc = concat!("let rng = ", stringify!($name), "::with_seed([0; 40]);")]
+            ///
+            /// let bytes = rng.gen::<64>();
+            ///
+            /// rng.set_word_pos(5);
+            ///
+            /// assert_eq!(rng.gen::<44>(), bytes[20..]);
+            /// ```
+            #[inline]
+            pub fn set_word_pos(&self, word_pos: u128) {
+                if self.try_set_word_pos(word_pos).is_err() {
+                    panic!("cannot seek while fast key erasure is enabled");
+                }
+            }
+
+            /// Seeks to the given `u32` word position in the keystream, allowing
+            /// random access to any part of the output. Only the lower 68 bits
+            /// of the position are used.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::KeyErasureEnabled`] without changing the
+            #[doc = concat!("generator if fast key erasure is enabled, see [`", stringify!($name), "::set_key_erasure`].")]
+            ///
+            /// # Example
+            /// ```
+            /// use turborand::{prelude::*, Error};
+            ///
+            #[doc = concat!("let rng = ", stringify!($name), "::with_seed([0; 40]);")]
+            ///
+            /// assert_eq!(rng.try_set_word_pos(5), Ok(()));
+            ///
+            /// rng.set_key_erasure(true);
+            ///
+            /// assert_eq!(rng.try_set_word_pos(5), Err(Error::KeyErasureEnabled));
+            /// ```
+            #[inline]
+            pub fn try_set_word_pos(&self, word_pos: u128) -> Result<(), Error> {
+                self.0.try_set_word_pos(word_pos)
+            }
+
+            /// Returns a snapshot of the full state, including any buffered
+            #[doc = concat!("keystream, which can be passed to [`", stringify!($name), "::restore`] to roll")]
+            /// the generator back to this point.
+            ///
+            /// # Example
+            /// ```
+            /// use turborand::prelude::*;
+            ///
+            #[doc = concat!("let rng = ", stringify!($name), "::with_seed([0; 40]);")]
+            ///
+            /// rng.gen::<3>();
+            ///
+            /// let checkpoint = rng.snapshot();
+            /// let value = rng.u64(..);
+            ///
+            /// rng.restore(checkpoint);
+            ///
+            /// assert_eq!(rng.u64(..), value);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn snapshot(&self) -> $snapshot {
+                $snapshot(self.0.snapshot())
+            }
+
+            #[doc = concat!("Restores the generator to the state captured by [`", stringify!($name), "::snapshot`].")]
+            #[inline]
+            pub fn restore(&self, snapshot: $snapshot) {
+                self.0.restore(&snapshot.0);
+            }
+
+            /// Returns whether fast key erasure is enabled.
+            #[inline]
+            #[must_use]
+            pub fn get_key_erasure(&self) -> bool {
+                self.0.get_key_erasure()
+            }
+
+            /// Enables or disables fast key erasure, for backtracking resistance.
+            /// Following Bernstein's fast-key-erasure design, every refill of the
+            /// internal buffer then generates two blocks, overwriting the key
+            /// with the first half of the first block and using the rest as
+            /// output, while output is wiped from the buffer once read. Should
+            /// the state later be exposed, output handed out before then cannot
+            /// be recomputed from it.
+            ///
+            /// Enabling replaces the key straight away, discarding any buffered
+            /// output. This halves throughput, and as the key changes on every
+            /// refill, there is no fixed keystream to seek in: while enabled,
+            #[doc = concat!("[`", stringify!($name), "::set_word_pos`] and [`", stringify!($name), "::set_stream`] panic, their `try_`")]
+            /// counterparts return [`Error::KeyErasureEnabled`], and the word
+            /// position no longer tracks the output read.
+            ///
+            /// # Example
+            /// ```
+            /// use turborand::prelude::*;
+            ///
+            #[doc = concat!("let rng = ", stringify!($name), "::with_seed([0; 40]);")]
+            ///
+            /// rng.set_key_erasure(true);
+            ///
+            /// let secret = rng.gen::<32>();
+            ///
+            #[doc = concat!("let compromised = ", stringify!($name), "::with_seed([0; 40]);")]
+            ///
+            /// // Even with the full state, seeking back does not yield the secret.
+            /// compromised.restore(rng.snapshot());
+            /// compromised.set_key_erasure(false);
+            /// compromised.set_word_pos(0);
+            ///
+            /// assert_ne!(compromised.gen::<32>(), secret);
+            /// ```
+            #[inline]
+            pub fn set_key_erasure(&self, enabled: bool) {
+                self.0.set_key_erasure(enabled);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl $name {
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] with a randomised seed.")]
+            ///
+            /// # Warning
+            ///
+            /// When a [global seed](crate::global) is set, including through the
+            /// `TURBORAND_SEED` environment variable, the thread-local generator is
+            /// seeded from it instead of from entropy, so the output is predictable
+            /// and **must not** be used for keys or tokens. Check
+            /// [`global::seed`](crate::global::seed) before relying on it for secrets.
+            #[inline]
+            #[must_use]
+            pub fn new() -> Self {
+                $local.with(|local| local.with(|rng| rng.fork()))
+            }
+
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] with a randomised seed, or returns")]
+            /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+            /// be seeded.
+            #[inline]
+            pub fn try_new() -> Result<Self, Error> {
+                $local.with(|local| local.try_with(|rng| rng.fork()))
+            }
+
+            /// Reseeds the current thread-local generator.
+            #[inline]
+            pub fn reseed_local(seed: [u8; 40]) {
+                $local.with(|local| local.with(|rng| rng.reseed(seed)));
+            }
+
+            /// Runs the closure with the current thread-local generator seeded with
+            #[doc = concat!("`seed`, as if by [`", stringify!($name), "::with_seed`], so that every [`", stringify!($name), "::new`]")]
+            /// within it forks deterministically. The previous thread-local state
+            /// is restored afterwards, even if the closure panics.
+            ///
+            /// # Example
+            /// ```
+            /// use turborand::prelude::*;
+            ///
+            #[doc = concat!("let value = ", stringify!($name), "::with_local_seed([1; 40], || ", stringify!($name), "::new().u64(..));")]
+            ///
+            #[doc = concat!("assert_eq!(value, ", stringify!($name), "::with_seed([1; 40]).fork().u64(..));")]
+            /// ```
+            #[inline]
+            pub fn with_local_seed<R>(seed: [u8; 40], f: impl FnOnce() -> R) -> R {
+                let previous = Self::local_snapshot();
+
+                Self::restore_local(Self::with_seed(seed).snapshot());
+
+                restoring(|| Self::restore_local(previous), f)
+            }
+
+            /// Returns a snapshot of the current thread-local generator, which can be
+            #[doc = concat!("passed to [`", stringify!($name), "::restore_local`] to rewind it to this point.")]
+            #[inline]
+            #[must_use]
+            pub fn local_snapshot() -> $snapshot {
+                $local.with(|local| local.with(|rng| rng.snapshot()))
+            }
+
+            /// Restores the current thread-local generator to the state captured by
+            #[doc = concat!("[`", stringify!($name), "::local_snapshot`].")]
+            #[inline]
+            pub fn restore_local(snapshot: $snapshot) {
+                $local.with(|local| local.with(|rng| rng.restore(snapshot)));
+            }
+        }
+
+        impl TurboCore for $name {
+            #[inline]
+            fn fill_bytes(&self, buffer: &mut [u8]) {
+                self.0.fill(buffer);
+            }
+        }
+
+        impl GenCore for $name {
+            const GEN_KIND: TurboKind = TurboKind::SLOW;
+
+            #[inline]
+            fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+                self.0.rand()
+            }
+        }
+
+        impl SeededCore for $name {
+            type Seed = [u8; 40];
+
+            #[inline]
+            #[allow(unused_attributes)]
+            #[must_use]
+            fn with_seed(seed: Self::Seed) -> Self {
+                Self(<$source>::with_seed(AlignedSeed::from(seed)))
+            }
+
+            #[inline]
+            fn reseed(&self, seed: Self::Seed) {
+                self.0.reseed(AlignedSeed::from(seed));
+            }
+        }
+
+        impl ForkableCore for $name {
+            #[inline]
+            #[allow(unused_attributes)]
+            #[must_use]
+            fn fork(&self) -> Self {
+                Self(<$source>::with_seed(AlignedSeed::from(self.0.rand())))
+            }
+        }
+
+        impl LabelledForkCore for $name {
+            #[inline]
+            fn fork_with_label(&self, label: &[u8]) -> Self {
+                Self::with_seed(self.0.labelled_seed(label))
+            }
+        }
+
+        impl StreamableCore for $name {
+            /// Creates the generator for stream `index` of the given seed, by
+            /// setting the stream selector, which replaces the last 8 bytes of
+            /// the seed. Every `u64` index selects its own keystream of 2^70
+            /// bytes, so this never fails.
+            #[inline]
+            fn try_with_stream(seed: Self::Seed, index: u64) -> Result<Self, Error> {
+                let rng = Self::with_seed(seed);
+
+                rng.set_stream(index);
+
+                Ok(rng)
+            }
+        }
+
+        impl SecureCore for $name {}
+
+        #[cfg(feature = "std")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl Default for $name {
+            #[doc = concat!("Initialises a default instance of [`", stringify!($name), "`]. Warning, the default is")]
+            /// seeded with a randomly generated state, so this is **not** deterministic,
+            #[doc = concat!("unless a global seed is set. See [`", stringify!($name), "::new`].")]
+            ///
+            /// # Example
+            /// ```
+            /// use turborand::prelude::*;
+            ///
+            #[doc = concat!("let rng1 = ", stringify!($name), "::default();")]
+            #[doc = concat!("let rng2 = ", stringify!($name), "::default();")]
+            ///
+            /// assert_ne!(rng1.u64(..), rng2.u64(..));
+            /// ```
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        thread_local! {
+            static $local: LocalRng<$name> = const { LocalRng::new(stringify!($local)) };
+        }
+    };
+}
+
+chacha_rng!(
+    /// A Random Number generator, powered by the `ChaCha8` algorithm.
+    ChaChaRng,
+    ChaCha8,
+    ChaChaSnapshot,
+    SECURE
+);
+
+chacha_rng!(
+    /// A Random Number generator, powered by the `ChaCha12` algorithm.
+    /// Slower than [`ChaChaRng`], but with a larger security margin.
+    ChaCha12Rng,
+    ChaCha12,
+    ChaCha12Snapshot,
+    SECURE12
+);
+
+chacha_rng!(
+    /// A Random Number generator, powered by the `ChaCha20` algorithm, as
+    /// specified in [RFC 8439](https://www.rfc-editor.org/rfc/rfc8439).
+    /// The slowest variant, for when interoperability or the full round count
+    /// is required.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// // RFC 8439, Appendix A.1, Test Vector #1
+    /// let rng = ChaCha20Rng::with_seed([0; 40]);
+    ///
+    /// assert_eq!(rng.gen::<4>(), [0x76, 0xb8, 0xe0, 0xad]);
+    /// ```
+    ChaCha20Rng,
+    ChaCha20,
+    ChaCha20Snapshot,
+    SECURE20
+);
+
+/// A handle to the thread-local [`ChaChaRng`], which draws from it directly
+/// rather than forking a new generator as [`ChaChaRng::new`] does. The handle
+/// holds no state of its own, so it is free to create, and always draws from
+/// the generator of the thread it is used on.
+///
+/// # Warning
+///
+/// When a [global seed](crate::global) is set, including through the
+/// `TURBORAND_SEED` environment variable, the thread-local generator is
+/// seeded from it instead of from entropy, so the output is predictable and
+/// **must not** be used for keys or tokens.
+///
+/// # Panics
+///
+/// Generating values panics if the thread-local generator has yet to be
+/// seeded and no entropy is available.
+///
+/// # Example
+/// ```
+/// use turborand::prelude::*;
+///
+/// let rng = thread_chacha_rng();
+///
+/// let key = rng.gen::<32>();
+///
+/// assert_ne!(key, rng.gen::<32>());
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "fmt", derive(Debug))]
+pub struct ThreadChaChaRng;
+
+/// Returns a [`ThreadChaChaRng`] handle to the thread-local [`ChaChaRng`].
+/// See [`ThreadChaChaRng`] for how a global seed affects it.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[inline]
+#[must_use]
+pub fn thread_chacha_rng() -> ThreadChaChaRng {
+    ThreadChaChaRng
+}
+
+#[cfg(feature = "std")]
+impl TurboCore for ThreadChaChaRng {
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        SECURE.with(|local| local.with(|rng| rng.fill_bytes(buffer)));
+    }
+}
+
+#[cfg(feature = "std")]
+impl GenCore for ThreadChaChaRng {
+    const GEN_KIND: TurboKind = ChaChaRng::GEN_KIND;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        SECURE.with(|local| local.with(|rng| rng.gen()))
+    }
+}
+
+#[cfg(feature = "std")]
+impl SecureCore for ThreadChaChaRng {}
+
+/// A thread-safe Random Number generator, powered by the `ChaCha8` algorithm.
+///
+/// Wraps the same generator as [`ChaChaRng`] behind a lightweight spin lock, so
+/// that it can be shared between threads, such as behind an [`Arc`](std::sync::Arc).
+/// Given the same seed, it yields the same output as [`ChaChaRng`].
+///
+/// # Example
+/// ```
+/// use turborand::prelude::*;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let rand = Arc::new(SyncChaChaRng::with_seed([0; 40]));
+/// let rand2 = rand.clone();
+///
+/// let thread_01 = thread::spawn(move || rand.u64(..));
+/// let thread_02 = thread::spawn(move || rand2.u64(..));
+///
+/// assert_ne!(thread_01.join().unwrap(), thread_02.join().unwrap());
+/// ```
+#[cfg(feature = "atomic")]
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "fmt", derive(Debug))]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-#[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "chacha", feature = "atomic"))))]
 #[repr(transparent)]
-pub struct ChaChaRng(ChaCha8);
+pub struct SyncChaChaRng(SpinLock<ChaCha8>);
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "atomic"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl ChaChaRng {
-    /// Creates a new [`ChaChaRng`] with a randomised seed.
+impl SyncChaChaRng {
+    /// Creates a new [`SyncChaChaRng`] with a randomised seed, drawn from the
+    /// thread-local [`ChaChaRng`]. It is therefore predictable when a global
+    /// seed is set, as explained on [`ChaChaRng::new`].
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        SECURE.with(|rng| rng.fork())
+        Self::with_seed(SECURE.with(|local| local.with(|rng| rng.gen())))
+    }
+
+    /// Creates a new [`SyncChaChaRng`] with a randomised seed, or returns
+    /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+    /// be seeded.
+    #[inline]
+    pub fn try_new() -> Result<Self, Error> {
+        SECURE
+            .with(|local| local.try_with(|rng| rng.gen()))
+            .map(Self::with_seed)
+    }
+}
+
+#[cfg(feature = "atomic")]
+impl SyncChaChaRng {
+    /// Returns whether fast key erasure is enabled.
+    #[inline]
+    #[must_use]
+    pub fn get_key_erasure(&self) -> bool {
+        self.0.with(|source| source.get_key_erasure())
     }
 
-    /// Reseeds the current thread-local generator.
+    /// Enables or disables fast key erasure, for backtracking resistance.
+    /// See [`ChaChaRng::set_key_erasure`].
     #[inline]
-    pub fn reseed_local(seed: [u8; 40]) {
-        SECURE.with(|rng| rng.reseed(seed));
+    pub fn set_key_erasure(&self, enabled: bool) {
+        self.0.with(|source| source.set_key_erasure(enabled));
     }
 }
 
-impl TurboCore for ChaChaRng {
+#[cfg(feature = "atomic")]
+impl TurboCore for SyncChaChaRng {
     #[inline]
     fn fill_bytes(&self, buffer: &mut [u8]) {
-        self.0.fill(buffer);
+        self.0.with(|source| source.fill(buffer));
     }
 }
 
-impl GenCore for ChaChaRng {
+#[cfg(feature = "atomic")]
+impl GenCore for SyncChaChaRng {
     const GEN_KIND: TurboKind = TurboKind::SLOW;
 
     #[inline]
     fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
-        self.0.rand()
+        self.0.with(|source| source.rand())
     }
 }
 
-impl SeededCore for ChaChaRng {
+#[cfg(feature = "atomic")]
+impl SeededCore for SyncChaChaRng {
     type Seed = [u8; 40];
 
     #[inline]
-    #[must_use]
     fn with_seed(seed: Self::Seed) -> Self {
-        Self(ChaCha8::with_seed(AlignedSeed::from(seed)))
+        Self(SpinLock::new(ChaCha8::with_seed(AlignedSeed::from(seed))))
     }
 
     #[inline]
     fn reseed(&self, seed: Self::Seed) {
-        self.0.reseed(AlignedSeed::from(seed));
+        self.0.with(|source| source.reseed(AlignedSeed::from(seed)));
     }
 }
 
-impl ForkableCore for ChaChaRng {
+#[cfg(feature = "atomic")]
+impl ForkableCore for SyncChaChaRng {
     #[inline]
-    #[must_use]
     fn fork(&self) -> Self {
-        Self(ChaCha8::with_seed(AlignedSeed::from(self.0.rand())))
+        Self::with_seed(self.gen())
     }
 }
 
-impl SecureCore for ChaChaRng {}
+#[cfg(feature = "atomic")]
+impl LabelledForkCore for SyncChaChaRng {
+    #[inline]
+    fn fork_with_label(&self, label: &[u8]) -> Self {
+        Self::with_seed(self.0.with(|source| source.labelled_seed(label)))
+    }
+}
 
-#[cfg(feature = "std")]
+#[cfg(feature = "atomic")]
+impl SecureCore for SyncChaChaRng {}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl Default for ChaChaRng {
-    /// Initialises a default instance of [`ChaChaRng`]. Warning, the default is
+impl Default for SyncChaChaRng {
+    /// Initialises a default instance of [`SyncChaChaRng`]. Warning, the default is
     /// seeded with a randomly generated state, so this is **not** deterministic.
     ///
     /// # Example
     /// ```
     /// use turborand::prelude::*;
     ///
-    /// let rng1 = ChaChaRng::default();
-    /// let rng2 = ChaChaRng::default();
+    /// let rng1 = SyncChaChaRng::default();
+    /// let rng2 = SyncChaChaRng::default();
     ///
     /// assert_ne!(rng1.u64(..), rng2.u64(..));
     /// ```
@@ -100,21 +632,258 @@ impl Default for ChaChaRng {
     }
 }
 
-#[cfg(feature = "std")]
-thread_local! {
-    static SECURE: Rc<ChaChaRng> = Rc::new(ChaChaRng::with_seed(generate_entropy()));
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn local_seed_is_scoped() {
+        let before = ChaCha20Rng::local_snapshot();
+
+        let forked = ChaCha20Rng::with_local_seed([9; 40], ChaCha20Rng::new);
+
+        assert_eq!(forked, ChaCha20Rng::with_seed([9; 40]).fork());
+        assert_eq!(ChaCha20Rng::local_snapshot(), before);
+
+        let panicked = std::panic::catch_unwind(|| {
+            ChaCha20Rng::with_local_seed([9; 40], || panic!("closure should unwind"));
+        });
+
+        assert!(panicked.is_err());
+        assert_eq!(ChaCha20Rng::local_snapshot(), before);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn thread_chacha_rng_borrows_thread_local() {
+        let expected = ChaChaRng::with_seed([0; 40]);
+
+        expected.reseed([7; 40]);
+        ChaChaRng::reseed_local([7; 40]);
+
+        assert_eq!(thread_chacha_rng().gen::<24>(), expected.gen::<24>());
+
+        let mut buffer = [0u8; 80];
+        let mut expected_buffer = [0u8; 80];
+
+        thread_chacha_rng().fill_bytes(&mut buffer);
+        expected.fill_bytes(&mut expected_buffer);
+
+        assert_eq!(buffer, expected_buffer);
+    }
+
     #[cfg(feature = "fmt")]
     #[test]
     fn no_leaking_debug() {
         let rng = ChaChaRng::with_seed([0u8; 40]);
 
         assert_eq!(format!("{rng:?}"), "ChaChaRng(ChaCha8)");
+
+        let rng = ChaCha12Rng::with_seed([0u8; 40]);
+
+        assert_eq!(format!("{rng:?}"), "ChaCha12Rng(ChaCha12)");
+
+        let rng = ChaCha20Rng::with_seed([0u8; 40]);
+
+        assert_eq!(format!("{rng:?}"), "ChaCha20Rng(ChaCha20)");
+    }
+
+    #[test]
+    fn round_variants_share_seed_format() {
+        let seed = [7u8; 40];
+
+        let rng8 = ChaChaRng::with_seed(seed);
+        let rng12 = ChaCha12Rng::with_seed(seed);
+        let rng20 = ChaCha20Rng::with_seed(seed);
+
+        let output8 = rng8.gen::<16>();
+        let output12 = rng12.gen::<16>();
+        let output20 = rng20.gen::<16>();
+
+        assert_ne!(output8, output12);
+        assert_ne!(output12, output20);
+
+        rng20.reseed(seed);
+
+        assert_eq!(rng20.gen::<16>(), output20);
+    }
+
+    #[test]
+    fn labelled_forks_depend_on_position() {
+        let rng1 = ChaChaRng::with_seed([3u8; 40]);
+        let rng2 = ChaChaRng::with_seed([3u8; 40]);
+
+        let child = rng1.derive_child("physics");
+
+        assert_eq!(rng2.derive_child("physics"), child);
+        assert_ne!(rng1.derive_child("audio"), child);
+
+        // Labelled forks do not advance the parent.
+        assert_eq!(rng1.gen::<8>(), rng2.gen::<8>());
+
+        rng2.set_stream(1);
+
+        assert_ne!(rng2.derive_child("physics"), rng1.derive_child("physics"));
+
+        rng2.set_stream(0);
+        rng2.fork();
+
+        assert_ne!(rng2.derive_child("physics"), rng1.derive_child("physics"));
+    }
+
+    #[test]
+    fn labelled_forks_do_not_expose_parent_key() {
+        let rng = ChaChaRng::with_seed([3u8; 40]);
+
+        let seed = rng.0.labelled_seed(b"physics");
+
+        // The child seed is a hash of the parent's key, not a copy of it.
+        assert_ne!(&seed[..32], &[3u8; 32]);
+        assert_ne!(seed, rng.0.labelled_seed(b"physicS"));
+        assert_eq!(ChaChaRng::with_seed(seed), rng.derive_child("physics"));
+    }
+
+    #[test]
+    fn snapshot_restores_buffered_keystream() {
+        let rng = ChaChaRng::with_seed([9u8; 40]);
+
+        rng.gen::<13>();
+
+        let checkpoint = rng.snapshot();
+        let expected = rng.gen::<200>();
+
+        assert_ne!(rng.snapshot(), checkpoint);
+
+        rng.restore(checkpoint);
+
+        assert_eq!(rng.snapshot(), checkpoint);
+        assert_eq!(rng.gen::<200>(), expected);
+
+        // Restoring into a generator with a different seed also restores its key.
+        let other = ChaChaRng::with_seed([1u8; 40]);
+
+        other.restore(checkpoint);
+
+        assert_eq!(other.gen::<200>(), expected);
+    }
+
+    #[test]
+    fn key_erasure_prevents_backtracking() {
+        for key_erasure in [false, true] {
+            let rng = ChaChaRng::with_seed([4u8; 40]);
+
+            rng.set_key_erasure(key_erasure);
+
+            let past = rng.gen::<100>();
+
+            // Recompute output from a compromised state by seeking back
+            // through the keystream of the exposed key.
+            let compromised = ChaChaRng::with_seed([0u8; 40]);
+
+            compromised.restore(rng.snapshot());
+            compromised.set_key_erasure(false);
+
+            let recovered = (0..128).any(|word_pos| {
+                compromised.set_word_pos(word_pos);
+                compromised.gen::<32>() == past[..32]
+            });
+
+            assert_eq!(recovered, !key_erasure);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot seek while fast key erasure is enabled")]
+    fn key_erasure_rejects_seeking() {
+        let rng = ChaChaRng::with_seed([4u8; 40]);
+
+        rng.set_key_erasure(true);
+        rng.set_word_pos(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot seek while fast key erasure is enabled")]
+    fn key_erasure_rejects_stream_changes() {
+        let rng = ChaChaRng::with_seed([4u8; 40]);
+
+        rng.set_key_erasure(true);
+        rng.set_stream(1);
+    }
+
+    #[test]
+    fn key_erasure_rejects_seeking_without_changes() {
+        let rng = ChaChaRng::with_seed([4u8; 40]);
+
+        rng.set_key_erasure(true);
+        rng.gen::<3>();
+
+        let checkpoint = rng.snapshot();
+
+        assert_eq!(rng.try_set_stream(1), Err(Error::KeyErasureEnabled));
+        assert_eq!(rng.try_set_word_pos(5), Err(Error::KeyErasureEnabled));
+        assert_eq!(rng.snapshot(), checkpoint);
+    }
+
+    #[test]
+    fn streams_select_keystream() {
+        let streams = crate::RngStreams::<ChaChaRng>::new([3u8; 40]);
+
+        let stream = streams.stream(u64::MAX);
+
+        assert_eq!(stream.get_stream(), u64::MAX);
+        assert_eq!(stream.get_word_pos(), 0);
+        assert_ne!(streams.stream(0), streams.stream(1));
+    }
+
+    #[test]
+    fn seeking_mid_block() {
+        let rng = ChaChaRng::with_seed([5u8; 40]);
+
+        rng.set_stream(9);
+        rng.gen::<100>();
+
+        let word_pos = rng.get_word_pos();
+        let expected = rng.gen::<37>();
+
+        assert_eq!(word_pos, 25);
+
+        rng.gen::<1000>();
+        rng.set_word_pos(word_pos);
+
+        assert_eq!(rng.gen::<37>(), expected);
+        assert_eq!(rng.get_stream(), 9);
+    }
+
+    #[cfg(feature = "atomic")]
+    #[test]
+    fn sync_matches_unsync_output() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<SyncChaChaRng>();
+
+        let rng = ChaChaRng::with_seed([9u8; 40]);
+        let sync_rng = SyncChaChaRng::with_seed([9u8; 40]);
+
+        assert_eq!(rng.gen::<100>(), sync_rng.gen::<100>());
+        assert_eq!(rng.fork().gen::<16>(), sync_rng.fork().gen::<16>());
+        assert_eq!(sync_rng, sync_rng.clone());
+    }
+
+    #[cfg(all(feature = "fmt", feature = "atomic"))]
+    #[test]
+    fn sync_no_leaking_debug() {
+        let rng = SyncChaChaRng::with_seed([0u8; 40]);
+
+        assert_eq!(format!("{rng:?}"), "SyncChaChaRng(ChaCha8)");
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn sync_no_duplicate_outputs_under_contention() {
+        crate::internal::testing::assert_no_duplicate_outputs_under_contention(
+            SyncChaChaRng::with_seed([1u8; 40]),
+        );
     }
 
     #[cfg(feature = "serialize")]
@@ -212,4 +981,54 @@ mod tests {
             ],
         );
     }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn bincode_roundtrip() {
+        for key_erasure in [false, true] {
+            let rng = ChaChaRng::with_seed([7u8; 40]);
+
+            rng.set_key_erasure(key_erasure);
+            rng.gen::<16>();
+
+            let bytes = bincode::serialize(&(&rng, 42u32)).unwrap();
+            let (restored, trailing): (ChaChaRng, u32) = bincode::deserialize(&bytes).unwrap();
+
+            assert_eq!(trailing, 42);
+            assert_eq!(restored.get_key_erasure(), key_erasure);
+            assert_eq!(restored.gen::<64>(), rng.gen::<64>());
+        }
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn bincode_reads_two_field_format() {
+        let rng = ChaChaRng::with_seed([0u8; 40]);
+
+        rng.gen::<16>();
+
+        // The state and cache, as written before fast key erasure existed.
+        let state: [u32; 16] = [
+            1634760805, 857760878, 2036477234, 1797285236, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0,
+        ];
+        let cache: [u64; 8] = [
+            15438444565445410878,
+            11647726043916688255,
+            4287315583106450476,
+            2169171444139891480,
+            4715024415260232856,
+            1825766843798996127,
+            121745463539026481,
+            4827309107960445752,
+        ];
+        let bytes = bincode::serialize(&(state, cache, 16u64)).unwrap();
+
+        assert_eq!(bincode::serialize(&rng).unwrap(), bytes);
+
+        let restored: ChaChaRng = bincode::deserialize(&bytes).unwrap();
+
+        assert!(!restored.get_key_erasure());
+        assert_eq!(restored, rng);
+        assert_eq!(restored.gen::<64>(), rng.gen::<64>());
+    }
 }
@@ -0,0 +1,295 @@
+//! Conditioning functions for turning raw, possibly biased entropy into
+//! full-entropy seeds.
+//!
+//! Each function writes its output into a byte buffer, so it can be combined
+//! with [`seed_from`] to produce a seed for any [`SeededCore`](crate::SeededCore)
+//! generator whose seed implements [`SeedBytes`]. Bits are read and written in
+//! little-endian order, from the least significant bit of each byte upwards.
+//!
+//! * [`von_neumann`] removes bias from independent bits, at the cost of
+//!   discarding at least three quarters of them.
+//! * [`toeplitz`] is a seeded randomness extractor, requiring a uniformly
+//!   random key but no assumption on the input beyond its min-entropy.
+//! * [`hash_df`] is the SHA-256 `Hash_df` derivation function from
+//!   [NIST SP 800-90A](https://doi.org/10.6028/NIST.SP.800-90Ar1), compressing
+//!   input of any length into output of the requested length.
+//!
+//! # Example
+//! ```
+//! use turborand::{conditioning, prelude::*};
+//!
+//! // Noisy, biased samples collected from a sensor.
+//! let samples = [0b0110_1001u8; 256];
+//!
+//! let mut debiased = [0u8; 64];
+//!
+//! conditioning::von_neumann(&samples, &mut debiased).unwrap();
+//!
+//! let rng = Rng::with_seed(conditioning::seed_from(|seed| {
+//!     conditioning::hash_df(&debiased, seed)
+//! }).unwrap());
+//!
+//! let value = rng.u64(..);
+//! ```
+use crate::{
+    internal::sha256::{Sha256, DIGEST_SIZE},
+    Error, SeedBytes,
+};
+
+/// The maximum output length of [`hash_df`], in bytes.
+const HASH_DF_MAX_OUTPUT: usize = 255 * DIGEST_SIZE;
+
+#[inline]
+fn bit(bytes: &[u8], index: usize) -> bool {
+    bytes[index / 8] & (1 << (index % 8)) != 0
+}
+
+/// Builds a seed from the output of a conditioning function, which is given
+/// a buffer the size of the whole seed to fill.
+///
+/// # Errors
+///
+/// Returns the [`Error`] of the conditioning function, if it fails.
+///
+/// # Example
+/// ```
+/// use turborand::{conditioning, prelude::*};
+///
+/// let samples = [42u8; 128];
+///
+/// let seed: u64 = conditioning::seed_from(|seed| conditioning::hash_df(&samples, seed)).unwrap();
+///
+/// let rng = Rng::with_seed(seed);
+/// ```
+#[inline]
+pub fn seed_from<S: SeedBytes, F: FnOnce(&mut [u8]) -> Result<(), Error>>(
+    condition: F,
+) -> Result<S, Error> {
+    let mut condition = Some(condition);
+    let mut result = Ok(());
+
+    let seed = S::from_fill(|buffer| {
+        if let Some(condition) = condition.take() {
+            result = condition(buffer);
+        }
+    });
+
+    result.map(|_| seed)
+}
+
+/// Von Neumann debiaser. Input bits are taken in non-overlapping pairs, with
+/// `01` yielding `0`, `10` yielding `1`, and equal pairs discarded. Provided
+/// the input bits are independent, the output is unbiased regardless of the
+/// bias of the input.
+///
+/// # Errors
+///
+/// Returns [`Error::InsufficientInput`] if the input does not have enough
+/// unequal pairs of bits to fill the output.
+///
+/// # Example
+/// ```
+/// use turborand::conditioning;
+///
+/// let mut output = [0u8; 1];
+///
+/// conditioning::von_neumann(&[0b0110_0110, 0b1001_1001], &mut output).unwrap();
+///
+/// assert_eq!(output, [0b0101_1010]);
+/// ```
+pub fn von_neumann(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    let required = output.len() * 8;
+    let mut written = 0;
+
+    output.fill(0);
+
+    for pair in (0..input.len() * 8).step_by(2) {
+        if written == required {
+            break;
+        }
+
+        let first = bit(input, pair);
+
+        if first != bit(input, pair + 1) {
+            output[written / 8] |= u8::from(first) << (written % 8);
+            written += 1;
+        }
+    }
+
+    if written < required {
+        return Err(Error::InsufficientInput);
+    }
+
+    Ok(())
+}
+
+/// Toeplitz hash extractor. The input of `n` bits is multiplied over GF(2) by
+/// an `m` by `n` Toeplitz matrix, where `m` is the output size in bits. The
+/// matrix is defined by the `n + m - 1` bits of `key`, which must be uniformly
+/// random but may be public and reused. The output is close to uniform as
+/// long as the input has sufficiently more than `m` bits of min-entropy.
+///
+/// # Errors
+///
+/// Returns [`Error::InsufficientInput`] if the output is larger than the
+/// input, or if the key is shorter than `n + m - 1` bits.
+///
+/// # Example
+/// ```
+/// use turborand::conditioning;
+///
+/// let key = [1, 2, 3, 4, 5];
+/// let mut output = [0u8; 2];
+///
+/// conditioning::toeplitz(&key, &[0xde, 0xad, 0xbe], &mut output).unwrap();
+///
+/// assert_eq!(output, [0xe7, 0xa7]);
+/// ```
+pub fn toeplitz(key: &[u8], input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    let columns = input.len() * 8;
+    let rows = output.len() * 8;
+
+    if rows > columns || key.len() * 8 < (columns + rows).saturating_sub(1) {
+        return Err(Error::InsufficientInput);
+    }
+
+    output.fill(0);
+
+    for row in 0..rows {
+        let parity = (0..columns).fold(false, |parity, column| {
+            parity ^ (bit(key, row + columns - 1 - column) & bit(input, column))
+        });
+
+        output[row / 8] |= u8::from(parity) << (row % 8);
+    }
+
+    Ok(())
+}
+
+/// The `Hash_df` derivation function of
+/// [NIST SP 800-90A](https://doi.org/10.6028/NIST.SP.800-90Ar1), section
+/// 10.3.1, instantiated with SHA-256. Compresses input of any length into
+/// output of the requested length, spreading the input's entropy across it.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidRange`] if the output is longer than 8160 bytes.
+///
+/// # Example
+/// ```
+/// use turborand::conditioning;
+///
+/// let mut output = [0u8; 8];
+///
+/// conditioning::hash_df(b"abc", &mut output).unwrap();
+///
+/// assert_eq!(output, [0xbe, 0x5e, 0x00, 0xca, 0x5d, 0xd1, 0x6e, 0xee]);
+/// ```
+pub fn hash_df(input: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    if output.len() > HASH_DF_MAX_OUTPUT {
+        return Err(Error::InvalidRange);
+    }
+
+    let bits = ((output.len() * 8) as u32).to_be_bytes();
+
+    output
+        .chunks_mut(DIGEST_SIZE)
+        .zip(1..=u8::MAX)
+        .for_each(|(chunk, counter)| {
+            let mut hasher = Sha256::new();
+
+            hasher.update(&[counter]);
+            hasher.update(&bits);
+            hasher.update(input);
+
+            chunk.copy_from_slice(&hasher.finalize()[..chunk.len()]);
+        });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn von_neumann_discards_equal_pairs() {
+        let mut output = [0u8; 1];
+
+        assert_eq!(
+            von_neumann(&[0xff, 0x00, 0b0110_0110, 0b1001_1001], &mut output),
+            Ok(())
+        );
+        assert_eq!(output, [0b0101_1010]);
+
+        assert_eq!(
+            von_neumann(&[0xff, 0x00, 0b0110_0110], &mut output),
+            Err(Error::InsufficientInput)
+        );
+    }
+
+    #[test]
+    fn toeplitz_requires_enough_key_and_input() {
+        let mut output = [0u8; 2];
+
+        assert_eq!(
+            toeplitz(&[1, 2, 3, 4], &[0xde, 0xad, 0xbe], &mut output),
+            Err(Error::InsufficientInput)
+        );
+        assert_eq!(
+            toeplitz(&[0; 8], &[0xde], &mut output),
+            Err(Error::InsufficientInput)
+        );
+    }
+
+    #[test]
+    fn toeplitz_is_linear() {
+        let key = [0x5a; 8];
+        let input1 = [0x12, 0x34, 0x56];
+        let input2 = [0xab, 0xcd, 0xef];
+        let combined: [u8; 3] = core::array::from_fn(|index| input1[index] ^ input2[index]);
+
+        let mut output1 = [0u8; 2];
+        let mut output2 = [0u8; 2];
+        let mut output3 = [0u8; 2];
+
+        toeplitz(&key, &input1, &mut output1).unwrap();
+        toeplitz(&key, &input2, &mut output2).unwrap();
+        toeplitz(&key, &combined, &mut output3).unwrap();
+
+        assert_eq!([output1[0] ^ output2[0], output1[1] ^ output2[1]], output3);
+    }
+
+    #[test]
+    fn hash_df_multiple_blocks() {
+        let mut output = [0u8; 40];
+
+        hash_df(b"abc", &mut output).unwrap();
+
+        assert_eq!(
+            output,
+            [
+                0x7a, 0x1a, 0x2c, 0x0b, 0xc2, 0x8d, 0x83, 0x40, 0xb6, 0xba, 0x32, 0x58, 0xf7, 0xed,
+                0x81, 0x60, 0x77, 0xb3, 0x8a, 0xfa, 0x10, 0x2a, 0x6b, 0xf8, 0xfd, 0x56, 0x2a, 0x71,
+                0xd8, 0x6b, 0x48, 0x9d, 0x7f, 0x4a, 0xe3, 0xb9, 0xb6, 0xbd, 0x80, 0x1a
+            ]
+        );
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn seeds_chacha_rng() {
+        use crate::{chacha_rng::ChaChaRng, GenCore, SeededCore};
+
+        let seed: [u8; 40] = seed_from(|seed| hash_df(b"abc", seed)).unwrap();
+
+        let rng1 = ChaChaRng::with_seed(seed);
+        let rng2 = ChaChaRng::with_seed(seed_from(|seed| hash_df(b"abc", seed)).unwrap());
+
+        assert_eq!(rng1.gen_u64(), rng2.gen_u64());
+        assert_eq!(
+            seed_from::<[u8; 40], _>(|seed| von_neumann(&[0xff; 64], seed)),
+            Err(Error::InsufficientInput)
+        );
+    }
+}
@@ -1,18 +1,102 @@
+//! Sources of entropy for seeding the thread-local generators.
+//!
+//! By default, [`OsEntropy`] is used, sampling entropy from the OS and running
+//! continuous health tests over it, as per
+//! [NIST SP 800-90B](https://doi.org/10.6028/NIST.SP.800-90B). Other sources
+//! can be checked the same way by wrapping them in [`HealthChecked`]. A custom
+//! [`EntropySource`] can be registered for the whole process with
+//! [`set_global_source`], which then seeds every thread-local generator
+//! initialised afterwards, and so every `new()` constructor forking from them.
+//!
+//! # Example
+//! ```
+//! use turborand::{entropy, prelude::*};
+//!
+//! struct Fixed;
+//!
+//! impl EntropySource for Fixed {
+//!     fn fill_entropy(&self, buffer: &mut [u8]) {
+//!         buffer.fill(7);
+//!     }
+//! }
+//!
+//! entropy::set_global_source(Fixed);
+//!
+//! // Thread-locals are initialised lazily, so a new thread picks up the source.
+//! let value = std::thread::spawn(|| Rng::new().u64(..)).join().unwrap();
+//!
+//! assert_eq!(value, Rng::from_entropy_source(&Fixed).fork().u64(..));
+//!
+//! entropy::reset_global_source();
+//! ```
+use std::{
+    cell::{Cell, OnceCell},
+    sync::{Arc, Mutex, PoisonError, RwLock},
+};
+
+#[cfg(all(unix, not(miri)))]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Once,
+};
+
+#[cfg(not(feature = "jitter"))]
 use std::{
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
     thread,
 };
 
+use crate::{
+    global,
+    internal::health::{HealthTests, STARTUP_SAMPLES},
+    EntropySource, Error, SeedBytes, SeededCore,
+};
+
+#[cfg(not(feature = "jitter"))]
 use crate::Instant;
 
-use getrandom::{getrandom, Error};
+#[cfg(feature = "fmt")]
+use crate::Debug;
+
+use getrandom::getrandom;
+
+#[cfg(feature = "jitter")]
+mod jitter;
+
+#[cfg(feature = "jitter")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jitter")))]
+pub use jitter::JitterEntropy;
+
+type GlobalSource = Arc<dyn EntropySource + Send + Sync>;
+
+static GLOBAL_SOURCE: RwLock<Option<GlobalSource>> = RwLock::new(None);
+
+/// Returns the registered global source, if any. The lock is released before
+/// returning, so that sampling the source neither blocks registrations nor
+/// deadlocks should the source itself register a new one.
+#[inline]
+fn global_source() -> Option<GlobalSource> {
+    GLOBAL_SOURCE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// This is a fallback in case other sources are not available, collecting
+/// entropy from CPU timing jitter.
+#[cfg(feature = "jitter")]
+#[inline(never)]
+fn fallback_entropy(buffer: &mut [u8]) -> Result<(), Error> {
+    JitterEntropy.try_fill_entropy(buffer)
+}
 
 /// This is a fallback in case other sources are not available. It is not meant
 /// to be super secure, but to provide at least something in case of absolute
 /// failure.
+#[cfg(not(feature = "jitter"))]
 #[inline(never)]
-fn fallback_entropy<B: AsMut<[u8]>>(mut buffer: B) -> Result<(), Error> {
+fn fallback_entropy(mut buffer: &mut [u8]) -> Result<(), Error> {
     // If we reach this point, RandomState is unlikely to be random, as
     // not even getrandom can yield valid entropy sources. So don't bother
     // and instead find other means of generating entropy from available sources.
@@ -20,8 +104,6 @@ fn fallback_entropy<B: AsMut<[u8]>>(mut buffer: B) -> Result<(), Error> {
     Instant::now().hash(&mut hasher);
     thread::current().id().hash(&mut hasher);
 
-    let mut buffer = buffer.as_mut();
-
     while !buffer.is_empty() {
         buffer.len().hash(&mut hasher);
         let output = hasher.finish().to_ne_bytes();
@@ -34,16 +116,311 @@ fn fallback_entropy<B: AsMut<[u8]>>(mut buffer: B) -> Result<(), Error> {
     Ok(())
 }
 
-/// Generates a random buffer from some OS/Hardware sources
-/// of entropy. Fallback provided in case OS/Hardware sources fail.
-pub(crate) fn generate_entropy<const SIZE: usize>() -> [u8; SIZE] {
-    let mut bytes = [0u8; SIZE];
+/// Raw OS/Hardware entropy, with the fallback in case those fail.
+struct SystemEntropy;
 
-    getrandom(&mut bytes)
-        .or_else(|_| fallback_entropy(&mut bytes))
-        .expect("Entropy sources should be available and not fail in order to sample random data");
+impl EntropySource for SystemEntropy {
+    #[inline]
+    fn fill_entropy(&self, buffer: &mut [u8]) {
+        self.try_fill_entropy(buffer).expect(
+            "Entropy sources should be available and not fail in order to sample random data",
+        );
+    }
 
-    bytes
+    #[inline]
+    fn try_fill_entropy(&self, buffer: &mut [u8]) -> Result<(), Error> {
+        getrandom(buffer).or_else(|_| fallback_entropy(buffer))
+    }
+}
+
+static OS_SOURCE: HealthChecked<SystemEntropy> = HealthChecked::new(SystemEntropy);
+
+/// The default [`EntropySource`], sampling from OS/Hardware sources of
+/// entropy. A fallback is provided in case OS/Hardware sources fail.
+///
+/// All sampled output is passed through the process-wide [`HealthChecked`]
+/// tests, so a broken backend, such as one yielding only zeroes, is reported
+/// as [`Error::UnhealthyEntropy`] by [`EntropySource::try_fill_entropy`]
+/// rather than being used as a seed.
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "fmt", derive(Debug))]
+pub struct OsEntropy;
+
+impl EntropySource for OsEntropy {
+    #[inline]
+    fn fill_entropy(&self, buffer: &mut [u8]) {
+        OS_SOURCE.fill_entropy(buffer);
+    }
+
+    #[inline]
+    fn try_fill_entropy(&self, buffer: &mut [u8]) -> Result<(), Error> {
+        OS_SOURCE.try_fill_entropy(buffer)
+    }
+}
+
+/// Wraps an [`EntropySource`] with the continuous health tests of
+/// [NIST SP 800-90B](https://doi.org/10.6028/NIST.SP.800-90B): the
+/// Repetition Count Test and the Adaptive Proportion Test.
+///
+/// Before any output is accepted, start-up tests are run over 1024 bytes
+/// sampled from the source and then discarded. Afterwards, all sampled output
+/// is tested continuously. If a test fails, [`EntropySource::try_fill_entropy`]
+/// returns [`Error::UnhealthyEntropy`] and the start-up tests are required
+/// again, whereas [`EntropySource::fill_entropy`] panics.
+///
+/// # Example
+/// ```
+/// use turborand::{entropy::HealthChecked, prelude::*, Error};
+///
+/// struct Stuck;
+///
+/// impl EntropySource for Stuck {
+///     fn fill_entropy(&self, buffer: &mut [u8]) {
+///         buffer.fill(0);
+///     }
+/// }
+///
+/// let source = HealthChecked::new(Stuck);
+///
+/// assert_eq!(
+///     Rng::try_from_entropy_source(&source),
+///     Err(Error::UnhealthyEntropy)
+/// );
+/// ```
+pub struct HealthChecked<E> {
+    source: E,
+    tests: Mutex<HealthTests>,
+}
+
+impl<E: EntropySource> HealthChecked<E> {
+    /// Wraps the given [`EntropySource`] so that its output is health tested.
+    #[inline]
+    #[must_use]
+    pub const fn new(source: E) -> Self {
+        Self {
+            source,
+            tests: Mutex::new(HealthTests::new()),
+        }
+    }
+
+    /// Unwraps the inner [`EntropySource`].
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.source
+    }
+}
+
+impl<E: EntropySource> EntropySource for HealthChecked<E> {
+    #[inline]
+    fn fill_entropy(&self, buffer: &mut [u8]) {
+        self.try_fill_entropy(buffer)
+            .expect("Entropy source should pass its health tests in order to sample random data");
+    }
+
+    #[inline]
+    fn try_fill_entropy(&self, buffer: &mut [u8]) -> Result<(), Error> {
+        let mut tests = self.tests.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if !tests.is_started() {
+            let mut samples = [0u8; STARTUP_SAMPLES];
+
+            self.source.try_fill_entropy(&mut samples)?;
+            tests.startup(&samples)?;
+        }
+
+        self.source.try_fill_entropy(buffer)?;
+        tests.check(buffer)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<E: Debug> Debug for HealthChecked<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("HealthChecked").field(&self.source).finish()
+    }
+}
+
+/// Registers an [`EntropySource`] to be used by the whole process in place
+/// of [`OsEntropy`], replacing any previously registered source.
+///
+/// Thread-local generators are seeded lazily on their first use in each
+/// thread, so only those not yet initialised will draw from the new source.
+pub fn set_global_source<E: EntropySource + Send + Sync + 'static>(source: E) {
+    *GLOBAL_SOURCE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(source));
+}
+
+/// Removes any registered global [`EntropySource`], reverting to [`OsEntropy`].
+pub fn reset_global_source() {
+    *GLOBAL_SOURCE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Draws from the registered global [`EntropySource`], or from [`OsEntropy`]
+/// if none has been registered. Used for seeding the thread-local generators.
+pub(crate) struct GlobalEntropy;
+
+impl EntropySource for GlobalEntropy {
+    #[inline]
+    fn fill_entropy(&self, buffer: &mut [u8]) {
+        match global_source() {
+            Some(source) => source.fill_entropy(buffer),
+            None => OsEntropy.fill_entropy(buffer),
+        }
+    }
+
+    #[inline]
+    fn try_fill_entropy(&self, buffer: &mut [u8]) -> Result<(), Error> {
+        match global_source() {
+            Some(source) => source.try_fill_entropy(buffer),
+            None => OsEntropy.try_fill_entropy(buffer),
+        }
+    }
+}
+
+/// Number of times the process has been forked, as counted in the child.
+#[cfg(all(unix, not(miri)))]
+static FORK_GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(all(unix, not(miri)))]
+static FORK_HANDLER: Once = Once::new();
+
+#[cfg(all(unix, not(miri)))]
+extern "C" fn on_fork_child() {
+    FORK_GENERATION.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Returns a value that changes whenever the process is forked, so that
+/// thread-local state copied into a child process can be detected.
+#[cfg(all(unix, not(miri)))]
+#[inline]
+fn fork_generation() -> usize {
+    FORK_HANDLER.call_once(|| {
+        // SAFETY: The handler only performs an atomic increment, which is
+        // async-signal-safe and so fine to run in the child after a fork.
+        // The handler is a plain function, valid for the whole program.
+        unsafe {
+            libc::pthread_atfork(None, None, Some(on_fork_child));
+        }
+    });
+
+    FORK_GENERATION.load(Ordering::Relaxed)
+}
+
+/// Miri cannot run `pthread_atfork`, so forks go undetected under it.
+#[cfg(any(not(unix), miri))]
+#[inline]
+fn fork_generation() -> usize {
+    0
+}
+
+/// A lazily seeded thread-local generator. Seeding is deferred until first
+/// use rather than done on thread-local initialisation, so that a failure to
+/// sample entropy can be reported by the `try_new` constructors.
+///
+/// As thread-locals are copied verbatim into a forked child process, the
+/// generator is reseeded when used in a different process than the one it was
+/// seeded in, so that parent and child do not yield the same values. It is
+/// likewise reseeded when the [global seed](crate::global) changes, and derives
+/// its seed from it under its `label` when one is set.
+pub(crate) struct LocalRng<T> {
+    rng: OnceCell<T>,
+    generation: Cell<usize>,
+    label: &'static str,
+}
+
+impl<T: SeededCore> LocalRng<T>
+where
+    T::Seed: SeedBytes,
+{
+    #[inline]
+    pub(crate) const fn new(label: &'static str) -> Self {
+        Self {
+            rng: OnceCell::new(),
+            generation: Cell::new(0),
+            label,
+        }
+    }
+
+    /// Returns a seed derived from the global seed if one is set, or sampled
+    /// from [`GlobalEntropy`] otherwise.
+    fn fresh_seed(&self, forks: usize) -> Result<T::Seed, Error> {
+        if let Some(seed) = global::thread_seed(self.label, forks) {
+            return Ok(seed);
+        }
+
+        let mut result = Ok(());
+
+        let seed = T::Seed::from_fill(|buffer| {
+            if result.is_ok() {
+                result = GlobalEntropy.try_fill_entropy(buffer);
+            }
+        });
+
+        result.map(|_| seed)
+    }
+
+    /// Runs the closure with the thread-local generator, seeding it first if
+    /// it has not been used yet, or reseeding it if the process has been
+    /// forked or the global seed has changed since.
+    #[inline]
+    pub(crate) fn try_with<R, F: FnOnce(&T) -> R>(&self, f: F) -> Result<R, Error> {
+        let forks = fork_generation();
+        let generation = forks.wrapping_add(global::epoch());
+
+        let rng = match self.rng.get() {
+            Some(rng) => {
+                if self.generation.get() != generation {
+                    rng.reseed(self.fresh_seed(forks)?);
+                    self.generation.set(generation);
+                }
+
+                rng
+            }
+            None => {
+                let rng = T::with_seed(self.fresh_seed(forks)?);
+
+                self.generation.set(generation);
+                self.rng.get_or_init(|| rng)
+            }
+        };
+
+        Ok(f(rng))
+    }
+
+    /// Runs the closure with the thread-local generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator has yet to be seeded, or must be reseeded after
+    /// a fork, and no entropy is available.
+    #[inline]
+    pub(crate) fn with<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        self.try_with(f).expect(
+            "Entropy sources should be available and not fail in order to sample random data",
+        )
+    }
+}
+
+/// Calls `f`, then calls `restore` once `f` has returned or unwound, for
+/// scoped overrides of the thread-local generators.
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub(crate) fn restoring<R>(restore: impl FnOnce(), f: impl FnOnce() -> R) -> R {
+    struct Guard<F: FnOnce()>(Option<F>);
+
+    impl<F: FnOnce()> Drop for Guard<F> {
+        fn drop(&mut self) {
+            if let Some(restore) = self.0.take() {
+                restore();
+            }
+        }
+    }
+
+    let _guard = Guard(Some(restore));
+
+    f()
 }
 
 #[cfg(test)]
@@ -63,6 +440,50 @@ mod tests {
         );
     }
 
+    /// Test double yielding the byte offsets as entropy, so that draws are
+    /// deterministic regardless of how many are made.
+    struct OffsetSource;
+
+    impl EntropySource for OffsetSource {
+        fn fill_entropy(&self, buffer: &mut [u8]) {
+            buffer
+                .iter_mut()
+                .enumerate()
+                .for_each(|(offset, byte)| *byte = offset as u8);
+        }
+    }
+
+    struct ZeroSource;
+
+    impl EntropySource for ZeroSource {
+        fn fill_entropy(&self, buffer: &mut [u8]) {
+            buffer.fill(0);
+        }
+    }
+
+    #[test]
+    fn health_checks_detect_faulty_source() {
+        let mut buffer = [0u8; 40];
+
+        assert_eq!(
+            HealthChecked::new(ZeroSource).try_fill_entropy(&mut buffer),
+            Err(Error::UnhealthyEntropy)
+        );
+        assert_eq!(
+            HealthChecked::new(OffsetSource).try_fill_entropy(&mut buffer),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn os_entropy_passes_health_checks() {
+        let mut buffer = [0u8; 64];
+
+        for _ in 0..16 {
+            assert_eq!(OsEntropy.try_fill_entropy(&mut buffer), Ok(()));
+        }
+    }
+
     #[test]
     fn large_fallback_entropy_source() {
         let mut result = [0u8; { core::mem::size_of::<u128>() }];
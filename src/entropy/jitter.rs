@@ -0,0 +1,282 @@
+//! CPU execution timing jitter collector, modelled on
+//! [jitterentropy](https://www.chronox.de/jent/).
+use std::sync::OnceLock;
+
+use crate::{
+    internal::sha256::{Sha256, DIGEST_SIZE},
+    EntropySource, Error, Instant,
+};
+
+/// Oversampling rate, the number of timing samples collected per bit of
+/// output, as each sample is only credited with a fraction of a bit.
+const OVERSAMPLING_RATE: usize = 3;
+
+/// Number of timing samples hashed into each output block.
+const SAMPLES_PER_BLOCK: usize = DIGEST_SIZE * 8 * OVERSAMPLING_RATE;
+
+/// Size of the memory region walked between timestamps, so that cache and
+/// memory access latencies contribute to the jitter.
+const MEMORY_SIZE: usize = 1 << 16;
+
+/// Stride of the memory walk, larger than common cache line sizes.
+const MEMORY_BLOCK_SIZE: usize = 128;
+
+/// Minimum number of memory accesses per sample.
+const MEMORY_ACCESS_LOOPS: usize = 128;
+
+/// Number of samples measured by the start-up self-tests.
+const SELF_TEST_SAMPLES: usize = 1024;
+
+/// Consecutive stuck samples tolerated before the noise source is considered
+/// broken, as per jitterentropy's repetition count test.
+const STUCK_CUTOFF: usize = 30 * OVERSAMPLING_RATE;
+
+/// Common divisor of the timer deltas found by a passing self-test. Failures
+/// are not cached, so a transient failure can be retried.
+static TIMER_GCD: OnceLock<u64> = OnceLock::new();
+
+/// An [`EntropySource`] collecting entropy from the timing jitter of CPU
+/// execution and memory accesses, for systems where OS entropy may be
+/// unavailable, such as minimal containers early in boot.
+///
+/// Many timing deltas of memory access loops are measured and conditioned
+/// through SHA-256, oversampling so that each output bit is backed by several
+/// samples. The timer is checked by self-tests on first use, failing if it is
+/// too coarse, goes backwards or yields too many stuck samples, and samples
+/// are continuously checked for getting stuck thereafter. As in jitterentropy,
+/// deltas are divided by their greatest common divisor, so that timers
+/// ticking in steps larger than a nanosecond are judged by their real
+/// resolution.
+///
+/// # Example
+/// ```
+/// use turborand::{entropy::JitterEntropy, prelude::*};
+///
+/// let rng = Rng::try_from_entropy_source(&JitterEntropy)
+///     .expect("timer should be fine-grained enough");
+///
+/// let value = rng.u64(..);
+/// ```
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "fmt", derive(Debug))]
+pub struct JitterEntropy;
+
+impl EntropySource for JitterEntropy {
+    #[inline]
+    fn fill_entropy(&self, buffer: &mut [u8]) {
+        self.try_fill_entropy(buffer)
+            .expect("Timing jitter should be measurable in order to sample random data");
+    }
+
+    fn try_fill_entropy(&self, buffer: &mut [u8]) -> Result<(), Error> {
+        let gcd = match TIMER_GCD.get() {
+            Some(&gcd) => gcd,
+            None => {
+                let gcd = Collector::new(1).self_test()?;
+
+                *TIMER_GCD.get_or_init(|| gcd)
+            }
+        };
+
+        let mut collector = Collector::new(gcd);
+
+        buffer.chunks_mut(DIGEST_SIZE).try_for_each(|chunk| {
+            let block = collector.generate_block()?;
+
+            chunk.copy_from_slice(&block[..chunk.len()]);
+
+            Ok(())
+        })
+    }
+}
+
+struct Collector {
+    memory: Vec<u8>,
+    location: usize,
+    base: Instant,
+    previous_time: u64,
+    previous_delta: u64,
+    previous_delta2: u64,
+    gcd: u64,
+    stuck_run: usize,
+    pool: [u8; DIGEST_SIZE],
+}
+
+impl Collector {
+    /// Creates a collector dividing timer deltas by `gcd`, the timer's
+    /// resolution as found by [`Collector::self_test`].
+    fn new(gcd: u64) -> Self {
+        let mut collector = Self {
+            memory: vec![0; MEMORY_SIZE],
+            location: 0,
+            base: Instant::now(),
+            previous_time: 0,
+            previous_delta: 0,
+            previous_delta2: 0,
+            gcd,
+            stuck_run: 0,
+            pool: [0; DIGEST_SIZE],
+        };
+
+        // Prime the delta history so the first samples are not spuriously stuck.
+        collector.measure();
+        collector.measure();
+
+        collector
+    }
+
+    #[inline]
+    fn now(&self) -> u64 {
+        self.base.elapsed().as_nanos() as u64
+    }
+
+    /// Walks the memory region, with the walk length varied by the timer
+    /// itself, so that cache state adds to the variation between samples.
+    #[inline(never)]
+    fn memory_access(&mut self) {
+        let loops = MEMORY_ACCESS_LOOPS + (self.previous_time as usize & 0x7f);
+
+        for _ in 0..loops {
+            self.location = (self.location + MEMORY_BLOCK_SIZE - 1) % MEMORY_SIZE;
+
+            let cell = &mut self.memory[self.location];
+            *cell = core::hint::black_box(cell.wrapping_add(1));
+        }
+    }
+
+    /// Measures one timing delta in units of the timer resolution, returning
+    /// it along with whether the sample is stuck, i.e. has a zero first,
+    /// second or third order derivative.
+    fn measure(&mut self) -> (u64, bool) {
+        self.memory_access();
+
+        let time = self.now();
+        let delta = time.wrapping_sub(self.previous_time) / self.gcd;
+        let delta2 = delta.wrapping_sub(self.previous_delta);
+        let delta3 = delta2.wrapping_sub(self.previous_delta2);
+
+        self.previous_time = time;
+        self.previous_delta = delta;
+        self.previous_delta2 = delta2;
+
+        (delta, delta == 0 || delta2 == 0 || delta3 == 0)
+    }
+
+    /// Checks that the timer is usable as a noise source, returning the
+    /// greatest common divisor of its deltas. Like jitterentropy's
+    /// `jent_gcd_analyze`, the timer is too coarse if the deltas, in units of
+    /// that divisor, vary by less than one on average.
+    fn self_test(mut self) -> Result<u64, Error> {
+        let mut backwards = 0;
+        let mut stuck = 0;
+        let mut gcd = 0;
+        let mut variation: u64 = 0;
+        let mut deltas = [0u64; SELF_TEST_SAMPLES];
+
+        for delta_slot in deltas.iter_mut() {
+            let previous = self.previous_time;
+            let (delta, is_stuck) = self.measure();
+
+            if self.previous_time < previous {
+                backwards += 1;
+            }
+
+            if delta == 0 {
+                return Err(Error::EntropyUnavailable);
+            }
+
+            *delta_slot = delta;
+            gcd = greatest_common_divisor(gcd, delta);
+            stuck += usize::from(is_stuck);
+        }
+
+        for pair in deltas.windows(2) {
+            variation = variation.saturating_add(pair[0].abs_diff(pair[1]) / gcd);
+        }
+
+        if backwards > 3 || variation < (SELF_TEST_SAMPLES - 1) as u64 {
+            return Err(Error::EntropyUnavailable);
+        }
+
+        if stuck > SELF_TEST_SAMPLES * 9 / 10 {
+            return Err(Error::UnhealthyEntropy);
+        }
+
+        Ok(gcd)
+    }
+
+    /// Collects enough samples for one block of output, conditioned together
+    /// with the previous block through SHA-256.
+    fn generate_block(&mut self) -> Result<[u8; DIGEST_SIZE], Error> {
+        let mut hasher = Sha256::new();
+
+        hasher.update(&self.pool);
+
+        let mut collected = 0;
+
+        while collected < SAMPLES_PER_BLOCK {
+            let (delta, stuck) = self.measure();
+
+            // Stuck samples are still mixed in, but are not credited.
+            hasher.update(&delta.to_le_bytes());
+
+            if stuck {
+                self.stuck_run += 1;
+
+                if self.stuck_run >= STUCK_CUTOFF {
+                    return Err(Error::UnhealthyEntropy);
+                }
+            } else {
+                self.stuck_run = 0;
+                collected += 1;
+            }
+        }
+
+        let output = hasher.finalize();
+
+        // Keep the pool separate from the output, so earlier outputs cannot be
+        // derived from later ones.
+        self.pool = Sha256::digest(&output);
+
+        Ok(output)
+    }
+}
+
+#[inline]
+fn greatest_common_divisor(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer_passes_self_tests() {
+        assert!(Collector::new(1).self_test().is_ok_and(|gcd| gcd > 0));
+    }
+
+    #[test]
+    fn greatest_common_divisor_of_coarse_ticks() {
+        assert_eq!(greatest_common_divisor(0, 300), 300);
+        assert_eq!(greatest_common_divisor(300, 1200), 300);
+        assert_eq!(greatest_common_divisor(300, 700), 100);
+        assert_eq!(greatest_common_divisor(7, 13), 1);
+    }
+
+    #[test]
+    fn collects_distinct_blocks() {
+        let mut buffer1 = [0u8; 48];
+        let mut buffer2 = [0u8; 48];
+
+        JitterEntropy.try_fill_entropy(&mut buffer1).unwrap();
+        JitterEntropy.try_fill_entropy(&mut buffer2).unwrap();
+
+        assert_ne!(buffer1, buffer2);
+        assert_ne!(&buffer1[..32], &buffer1[16..]);
+    }
+}
@@ -0,0 +1,57 @@
+use core::fmt::{Display, Formatter};
+
+/// Errors returned by the fallible constructors and `try_` methods of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The given range is empty or invalid.
+    InvalidRange,
+    /// The given rate is not a value between `0.0` and `1.0`.
+    InvalidRate,
+    /// The given radix is zero or greater than 36.
+    InvalidRadix,
+    /// No entropy could be sampled from the entropy source.
+    EntropyUnavailable,
+    /// The entropy source failed its continuous health tests, such as by
+    /// repeatedly yielding the same values.
+    UnhealthyEntropy,
+    /// The input or key material is too short to produce the requested output.
+    InsufficientInput,
+    /// The keystream cannot be seeked, as fast key erasure is enabled.
+    KeyErasureEnabled,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InvalidRange => "range is empty or invalid",
+            Self::InvalidRate => "rate value is not between 0.0 and 1.0",
+            Self::InvalidRadix => "radix is either zero or greater than 36",
+            Self::EntropyUnavailable => "entropy source failed to provide entropy",
+            Self::UnhealthyEntropy => "entropy source failed its health tests",
+            Self::InsufficientInput => "input is too short for the requested output",
+            Self::KeyErasureEnabled => "cannot seek while fast key erasure is enabled",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn error_messages() {
+        #[cfg(all(feature = "alloc", not(feature = "std")))]
+        use alloc::string::ToString;
+
+        assert_eq!(Error::InvalidRange.to_string(), "range is empty or invalid");
+        assert_eq!(
+            Error::EntropyUnavailable.to_string(),
+            "entropy source failed to provide entropy"
+        );
+    }
+}
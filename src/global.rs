@@ -0,0 +1,188 @@
+//! Process-wide deterministic mode, for reproducing runs of programs that
+//! create generators deep within code that cannot be given a seed.
+//!
+//! Once a global seed is set, either with [`set_seed`] or through the
+//! `TURBORAND_SEED` environment variable, the thread-local generators are no
+//! longer seeded from entropy. Instead, each thread derives its seeds from the
+//! global seed, its name and the number of threads of the same name seen
+//! before it, so that every `new()` constructor forking from them yields the
+//! same values from one run to the next. Threads sharing a name, such as
+//! unnamed worker threads, are told apart by the order in which they first
+//! seed a generator, so they are only reproducible if they do so in a fixed
+//! order.
+//!
+//! # Warning
+//!
+//! This covers the secure thread-locals too, so `ChaChaRng::new()`, its
+//! `Default` and `thread_chacha_rng()` become just as predictable as
+//! `Rng::new()`. Never set a global seed, or let `TURBORAND_SEED` through to
+//! the environment, in a process that generates keys, tokens or other secrets
+//! with them. Secrets can still be drawn from an
+//! [`EntropySource`](crate::EntropySource) such as
+//! [`OsEntropy`](crate::entropy::OsEntropy) with
+//! [`SeededCore::from_entropy_source`](crate::SeededCore::from_entropy_source),
+//! which is never affected by the global seed.
+//!
+//! `TURBORAND_SEED` is read the first time a seed is needed. A `u64` value is
+//! used as the seed directly, while any other value is hashed into one, and
+//! [`seed`] returns the seed in use so it can be logged and replayed.
+//!
+//! # Example
+//! ```
+//! use turborand::{global, prelude::*};
+//!
+//! global::set_seed(1234);
+//!
+//! // The same on every run of the program.
+//! let value = Rng::new().u64(..);
+//!
+//! println!("Reproduce with TURBORAND_SEED={}", global::seed().unwrap());
+//!
+//! global::reset_seed();
+//!
+//! assert_eq!(global::seed(), None);
+//! ```
+use std::{
+    cell::OnceCell,
+    collections::BTreeMap,
+    env,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, Once, PoisonError, RwLock,
+    },
+    thread,
+};
+
+use crate::{seed::byte_words, SeedBytes, SeedSequence};
+
+/// The environment variable read for a global seed.
+const SEED_VAR: &str = "TURBORAND_SEED";
+
+static SEED: RwLock<Option<u64>> = RwLock::new(None);
+
+static SEED_FROM_ENV: Once = Once::new();
+
+/// Incremented whenever the global seed changes, so that thread-local
+/// generators seeded before the change can tell they are out of date.
+static EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of threads of each name that have derived a seed so far.
+static NAMES_SEEN: Mutex<BTreeMap<String, u32>> = Mutex::new(BTreeMap::new());
+
+thread_local! {
+    static IDENTITY: OnceCell<(String, u32)> = const { OnceCell::new() };
+}
+
+/// Sets the global seed, making every thread-local generator deterministic.
+/// Generators already seeded are reseeded from the global seed on their next
+/// use, and the value takes precedence over `TURBORAND_SEED`.
+pub fn set_seed(seed: u64) {
+    SEED_FROM_ENV.call_once(|| {});
+
+    *SEED.write().unwrap_or_else(PoisonError::into_inner) = Some(seed);
+
+    EPOCH.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Removes the global seed, so that thread-local generators are seeded from
+/// entropy again, including those already seeded from the global seed.
+pub fn reset_seed() {
+    SEED_FROM_ENV.call_once(|| {});
+
+    *SEED.write().unwrap_or_else(PoisonError::into_inner) = None;
+
+    EPOCH.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Returns the global seed in use, whether set with [`set_seed`] or read from
+/// `TURBORAND_SEED`, or `None` if thread-local generators are seeded from
+/// entropy.
+#[must_use]
+pub fn seed() -> Option<u64> {
+    SEED_FROM_ENV.call_once(|| {
+        if let Some(value) = env::var_os(SEED_VAR) {
+            *SEED.write().unwrap_or_else(PoisonError::into_inner) =
+                Some(parse_seed(&value.to_string_lossy()));
+        }
+    });
+
+    *SEED.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns a value that changes whenever the global seed does.
+#[inline]
+pub(crate) fn epoch() -> usize {
+    EPOCH.load(Ordering::Relaxed)
+}
+
+/// Derives the seed of the thread-local generator identified by `label` for
+/// the current thread, or returns `None` if no global seed is set. `forks`
+/// tells apart the generators of a forked child from those of its parent.
+pub(crate) fn thread_seed<S: SeedBytes>(label: &str, forks: usize) -> Option<S> {
+    let seed = seed()?;
+
+    IDENTITY.with(|identity| {
+        let (name, index) = identity.get_or_init(identify);
+
+        let state = [seed as u32, (seed >> 32) as u32, *index, forks as u32]
+            .into_iter()
+            .chain(byte_words(name.as_bytes()));
+
+        Some(SeedSequence::labelled(state, label.as_bytes()).seed())
+    })
+}
+
+/// Uses a numeric value as the seed as is, and hashes any other value.
+fn parse_seed(value: &str) -> u64 {
+    let value = value.trim();
+
+    value
+        .parse()
+        .unwrap_or_else(|_| SeedSequence::from(value).seed())
+}
+
+/// Returns the name of the current thread, along with how many threads of the
+/// same name derived a seed before it.
+fn identify() -> (String, u32) {
+    let name = thread::current().name().unwrap_or_default().to_owned();
+
+    let mut seen = NAMES_SEEN.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let count = seen.entry(name.clone()).or_default();
+    let index = *count;
+
+    *count += 1;
+
+    (name, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numeric_and_textual_seeds() {
+        assert_eq!(parse_seed("1234"), 1234);
+        assert_eq!(parse_seed(" 1234\n"), 1234);
+        assert_eq!(parse_seed("ci-run"), parse_seed("ci-run"));
+        assert_ne!(parse_seed("ci-run"), parse_seed("ci-run-2"));
+    }
+
+    #[test]
+    fn threads_of_the_same_name_are_told_apart() {
+        let spawn = || {
+            thread::Builder::new()
+                .name("global-test".into())
+                .spawn(identify)
+                .unwrap()
+                .join()
+                .unwrap()
+        };
+
+        let (name, first) = spawn();
+        let (_, second) = spawn();
+
+        assert_eq!(name, "global-test");
+        assert_eq!(second, first + 1);
+    }
+}
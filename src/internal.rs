@@ -1,8 +1,33 @@
 //! Internal structs and traits for the `WyRand` PRNGs.
 #[cfg(feature = "chacha")]
 pub(crate) mod buffer;
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
+))]
+pub(crate) mod health;
 
+#[cfg(all(feature = "chacha", feature = "atomic"))]
+pub(crate) mod lock;
+#[cfg(all(feature = "std", feature = "atomic"))]
+pub(crate) mod padded;
+#[cfg(any(
+    feature = "chacha",
+    feature = "conditioning",
+    all(
+        feature = "jitter",
+        any(feature = "wyrand", feature = "xoshiro", feature = "pcg")
+    )
+))]
+pub(crate) mod sha256;
 #[cfg(feature = "wyrand")]
 pub(crate) mod state;
+#[cfg(all(test, feature = "std", feature = "atomic"))]
+pub(crate) mod testing;
 
 pub(crate) mod uniform;
@@ -3,6 +3,9 @@ use core::cell::UnsafeCell;
 #[cfg(feature = "serialize")]
 use crate::{Deserialize, Serialize, SerializeTuple, Visitor};
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 #[cfg_attr(feature = "fmt", derive(Debug))]
 pub(crate) struct EntropyBuffer<const SIZE: usize> {
     buffer: UnsafeCell<[u64; SIZE]>,
@@ -105,7 +108,7 @@ impl<const SIZE: usize> EntropyBuffer<SIZE> {
     /// the buffer by the value of the cursor. A zero value
     /// indicates an empty buffer.
     #[inline]
-    fn remaining_buffer(&self) -> usize {
+    pub(crate) fn remaining_buffer(&self) -> usize {
         Self::total_bytes() - self.get_cursor()
     }
 
@@ -150,6 +153,41 @@ impl<const SIZE: usize> EntropyBuffer<SIZE> {
         self.update_cursor(Self::total_bytes());
     }
 
+    /// Returns a copy of the stored entropy along with the cursor position.
+    #[inline]
+    pub(crate) fn snapshot(&self) -> ([u64; SIZE], usize) {
+        (*self.get_buffer(), self.get_cursor())
+    }
+
+    /// Overwrites the bytes already consumed from the buffer with zeroes, so
+    /// that output handed out earlier does not linger in memory.
+    #[inline]
+    pub(crate) fn erase_consumed(&self) {
+        let cursor = self.get_cursor();
+
+        // SAFETY: Data is always initialised, and the bytes are zeroed in
+        // place so that no copy of the unread entropy is left behind. The
+        // mutable reference only lives for the write, with no other
+        // references to the buffer alive. This can also cause data races if
+        // called from different threads, but EntropyBuffer is not Sync, so
+        // this won't happen.
+        let buffer = unsafe { &mut *self.buffer.get() };
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(buffer);
+
+        bytes[..cursor].fill(0);
+    }
+
+    /// Replaces the stored entropy with a new buffer state, placing the cursor
+    /// at the given byte offset so that the bytes before it are treated as
+    /// already consumed.
+    #[inline]
+    pub(crate) fn seek_entropy(&self, buffer: [u64; SIZE], cursor: usize) {
+        debug_assert!(cursor <= Self::total_bytes());
+
+        self.update_buffer(buffer);
+        self.update_cursor(cursor);
+    }
+
     /// Fills the incoming mutable byte source with available entropy, consuming
     /// the entropy stored in the buffer until it is exhausted and then pulling in
     /// more entropy when required to refill the buffer and finish filling the input
@@ -207,6 +245,15 @@ impl<const SIZE: usize> Default for EntropyBuffer<SIZE> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<const SIZE: usize> Drop for EntropyBuffer<SIZE> {
+    /// Wipes any unread entropy from memory.
+    fn drop(&mut self) {
+        self.buffer.get_mut().zeroize();
+        self.cursor.get_mut().zeroize();
+    }
+}
+
 impl<const SIZE: usize> Clone for EntropyBuffer<SIZE> {
     #[inline]
     fn clone(&self) -> Self {
@@ -278,6 +325,13 @@ impl<'de, const SIZE: usize> Deserialize<'de> for EntropyBuffer<SIZE> {
                     .next_element()?
                     .ok_or_else(|| serde::de::Error::invalid_length(len, &self))?;
 
+                if cursor > EntropyBuffer::<LENGTH>::total_bytes() {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(cursor as u64),
+                        &"a cursor within the buffer",
+                    ));
+                }
+
                 Ok(EntropyBuffer::from_serde(buf, cursor))
             }
         }
@@ -416,4 +470,15 @@ mod tests {
             ],
         );
     }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_rejects_out_of_bounds_cursor() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<EntropyBuffer<1>>(
+            &[Token::Tuple { len: 2 }, Token::U64(1), Token::U64(9)],
+            "invalid value: integer `9`, expected a cursor within the buffer",
+        );
+    }
 }
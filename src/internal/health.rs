@@ -0,0 +1,168 @@
+//! Continuous health tests for entropy sources, as specified by
+//! [NIST SP 800-90B](https://doi.org/10.6028/NIST.SP.800-90B), section 4.4.
+//!
+//! Samples are taken to be bytes, with an assessed min-entropy of
+//! [`MIN_ENTROPY`] bits each. This is a conservative claim for OS sources,
+//! which output conditioned data, so the false positive rate on a healthy
+//! source is far below the `2^-20` targeted by the cutoffs.
+use crate::Error;
+
+/// The assessed min-entropy, in bits, of each byte sample.
+const MIN_ENTROPY: usize = 4;
+
+/// Cutoff for the Repetition Count Test, `1 + ceil(20 / H)` for a false
+/// positive probability of `2^-20`.
+const REPETITION_CUTOFF: usize = 1 + 20_usize.div_ceil(MIN_ENTROPY);
+
+/// Window size of the Adaptive Proportion Test for non-binary samples.
+const WINDOW_SIZE: usize = 512;
+
+/// Cutoff for the Adaptive Proportion Test, `1 + CRITBINOM(512, 2^-4, 1 - 2^-20)`.
+const PROPORTION_CUTOFF: usize = 62;
+
+/// The number of samples to be tested on start-up before any output is used.
+pub(crate) const STARTUP_SAMPLES: usize = 1024;
+
+/// State for the Repetition Count and Adaptive Proportion tests, carried
+/// across calls so that the tests run continuously over all sampled output.
+pub(crate) struct HealthTests {
+    started: bool,
+    previous: u8,
+    repetitions: usize,
+    reference: u8,
+    window_index: usize,
+    occurrences: usize,
+}
+
+impl HealthTests {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            started: false,
+            previous: 0,
+            repetitions: 0,
+            reference: 0,
+            window_index: 0,
+            occurrences: 0,
+        }
+    }
+
+    /// Whether the start-up tests have been run and passed.
+    #[inline]
+    pub(crate) fn is_started(&self) -> bool {
+        self.started
+    }
+
+    /// Runs the start-up tests over the given samples, which are to be
+    /// discarded afterwards.
+    #[inline]
+    pub(crate) fn startup(&mut self, samples: &[u8]) -> Result<(), Error> {
+        self.check(samples)?;
+        self.started = true;
+
+        Ok(())
+    }
+
+    /// Feeds the samples through both tests. On failure, the state is reset so
+    /// that the start-up tests must be passed again before output is accepted.
+    #[inline]
+    pub(crate) fn check(&mut self, samples: &[u8]) -> Result<(), Error> {
+        let result = samples.iter().try_for_each(|&sample| {
+            self.repetition_count(sample)?;
+            self.adaptive_proportion(sample)
+        });
+
+        if result.is_err() {
+            *self = Self::new();
+        }
+
+        result
+    }
+
+    #[inline]
+    fn repetition_count(&mut self, sample: u8) -> Result<(), Error> {
+        if self.repetitions > 0 && sample == self.previous {
+            self.repetitions += 1;
+
+            if self.repetitions >= REPETITION_CUTOFF {
+                return Err(Error::UnhealthyEntropy);
+            }
+        } else {
+            self.previous = sample;
+            self.repetitions = 1;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn adaptive_proportion(&mut self, sample: u8) -> Result<(), Error> {
+        if self.window_index == 0 {
+            self.reference = sample;
+            self.occurrences = 1;
+        } else if sample == self.reference {
+            self.occurrences += 1;
+
+            if self.occurrences >= PROPORTION_CUTOFF {
+                return Err(Error::UnhealthyEntropy);
+            }
+        }
+
+        self.window_index = (self.window_index + 1) % WINDOW_SIZE;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycling_bytes<const SIZE: usize>() -> [u8; SIZE] {
+        core::array::from_fn(|index| (index as u8).wrapping_mul(31))
+    }
+
+    #[test]
+    fn healthy_samples_pass() {
+        let mut tests = HealthTests::new();
+
+        assert_eq!(tests.startup(&cycling_bytes::<STARTUP_SAMPLES>()), Ok(()));
+        assert!(tests.is_started());
+        assert_eq!(tests.check(&cycling_bytes::<4096>()), Ok(()));
+    }
+
+    #[test]
+    fn repetition_count_detects_stuck_source() {
+        let mut tests = HealthTests::new();
+
+        let mut samples = cycling_bytes::<64>();
+        samples[10..10 + REPETITION_CUTOFF - 1].fill(7);
+
+        assert_eq!(tests.check(&samples), Ok(()));
+
+        samples[10..10 + REPETITION_CUTOFF].fill(7);
+
+        assert_eq!(tests.check(&samples), Err(Error::UnhealthyEntropy));
+    }
+
+    #[test]
+    fn adaptive_proportion_detects_bias() {
+        let mut tests = HealthTests::new();
+
+        // Never repeats consecutively, but half the window is the same value.
+        let samples: [u8; WINDOW_SIZE] =
+            core::array::from_fn(|index| if index % 2 == 0 { 0 } else { index as u8 });
+
+        assert_eq!(tests.check(&samples), Err(Error::UnhealthyEntropy));
+    }
+
+    #[test]
+    fn failure_resets_startup() {
+        let mut tests = HealthTests::new();
+
+        tests.startup(&cycling_bytes::<STARTUP_SAMPLES>()).unwrap();
+
+        assert_eq!(tests.check(&[0; 16]), Err(Error::UnhealthyEntropy));
+        assert!(!tests.is_started());
+    }
+}
@@ -0,0 +1,210 @@
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+#[cfg(feature = "fmt")]
+use crate::Debug;
+
+#[cfg(feature = "serialize")]
+use crate::{Deserialize, Serialize};
+
+/// Number of exponentially growing rounds of spinning to back off for, before
+/// waiting threads start yielding to the scheduler instead.
+const BACKOFF_ROUNDS: u32 = 6;
+
+/// A minimal spin lock, for sharing non-[`Sync`] sources across threads
+/// without requiring `std`. Critical sections last a single generation or
+/// buffer fill call, so waiting threads first spin with an exponential
+/// backoff. Once that is exhausted they yield to the scheduler with `std`, so
+/// that a preempted lock holder is not starved by its waiters.
+pub(crate) struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: Access to the inner value is serialised by the lock, so only one
+// thread can ever reference it at a time. The value only needs to be `Send`
+// to be moved between threads in this manner.
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+/// Releases the lock when dropped, so that a panic while the lock is held
+/// does not leave it locked forever.
+struct SpinGuard<'a>(&'a AtomicBool);
+
+impl Drop for SpinGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+impl<T> SpinLock<T> {
+    #[inline]
+    pub(crate) const fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    #[inline]
+    fn lock(&self) -> SpinGuard<'_> {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            let mut round = 0;
+
+            while self.locked.load(Ordering::Relaxed) {
+                if round < BACKOFF_ROUNDS {
+                    (0..1 << round).for_each(|_| core::hint::spin_loop());
+                    round += 1;
+                } else {
+                    Self::relax();
+                }
+            }
+        }
+
+        SpinGuard(&self.locked)
+    }
+
+    /// Gives up the rest of the time slice to other threads, or just spins
+    /// when there is no scheduler to yield to.
+    #[inline]
+    fn relax() {
+        #[cfg(feature = "std")]
+        std::thread::yield_now();
+        #[cfg(not(feature = "std"))]
+        core::hint::spin_loop();
+    }
+
+    /// Acquires the lock and runs the closure with a reference to the inner
+    /// value, releasing the lock afterwards.
+    #[inline]
+    pub(crate) fn with<R, F: FnOnce(&T) -> R>(&self, f: F) -> R {
+        let _guard = self.lock();
+
+        // SAFETY: The lock is held for as long as the guard lives, which is
+        // longer than the reference handed to the closure, so no other thread
+        // can access the value at the same time. No mutable references to
+        // the value are ever created while the lock is held.
+        f(unsafe { &*self.value.get() })
+    }
+}
+
+impl<T: Clone> Clone for SpinLock<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.with(T::clone))
+    }
+}
+
+impl<T: PartialEq> PartialEq for SpinLock<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // Locking the same lock twice would never resolve.
+        if core::ptr::eq(self, other) {
+            return true;
+        }
+
+        // Take both locks in address order, so that `a == b` and `b == a`
+        // running at the same time can't each hold the lock the other needs.
+        if (self as *const Self) < (other as *const Self) {
+            self.with(|value| other.with(|other| value == other))
+        } else {
+            other.with(|other| self.with(|value| value == other))
+        }
+    }
+}
+
+impl<T: Eq> Eq for SpinLock<T> {}
+
+#[cfg(feature = "fmt")]
+impl<T: Debug> Debug for SpinLock<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.with(|value| value.fmt(f))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<T: Serialize> Serialize for SpinLock<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.with(|value| value.serialize(serializer))
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SpinLock<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_is_released() {
+        let lock = SpinLock::new(5u32);
+
+        assert_eq!(lock.with(|value| *value + 1), 6);
+        assert_eq!(lock.with(|value| *value), 5);
+        assert!(lock == lock.clone());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn lock_is_released_on_panic() {
+        let lock = std::sync::Arc::new(SpinLock::new(0u32));
+        let shared = lock.clone();
+
+        let result = std::thread::spawn(move || shared.with(|_| panic!("poisoned?"))).join();
+
+        assert!(result.is_err());
+        assert_eq!(lock.with(|value| *value), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn opposing_comparisons_do_not_deadlock() {
+        let a = SpinLock::new(1u32);
+        let b = SpinLock::new(1u32);
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| (0..10_000).for_each(|_| assert!(a == b)));
+            scope.spawn(|| (0..10_000).for_each(|_| assert!(b == a)));
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn oversubscribed_lock_makes_progress() {
+        use std::sync::atomic::AtomicUsize;
+
+        let lock = SpinLock::new(AtomicUsize::new(0));
+        let threads = 4 * std::thread::available_parallelism().map_or(1, usize::from);
+
+        std::thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    for _ in 0..1_000 {
+                        lock.with(|count| count.fetch_add(1, Ordering::Relaxed));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(
+            lock.with(|count| count.load(Ordering::Relaxed)),
+            threads * 1_000
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use core::ops::Deref;
+
+/// Aligns and pads the wrapped value to its own cache line, so that
+/// neighbouring values written to by different cores do not end up
+/// invalidating each other's cache line (false sharing).
+///
+/// 128 bytes is used as modern x86_64 and aarch64 CPUs prefetch cache lines
+/// in adjacent pairs.
+#[derive(Clone, PartialEq, Eq)]
+#[repr(align(128))]
+pub(crate) struct CachePadded<T>(T);
+
+impl<T> CachePadded<T> {
+    #[inline]
+    pub(crate) const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padded_to_cache_line() {
+        assert_eq!(core::mem::align_of::<CachePadded<u64>>(), 128);
+        assert_eq!(core::mem::size_of::<[CachePadded<u64>; 2]>(), 256);
+    }
+}
@@ -0,0 +1,206 @@
+//! A minimal SHA-256 implementation, as specified by
+//! [FIPS 180-4](https://doi.org/10.6028/NIST.FIPS.180-4), used for
+//! conditioning raw entropy samples and deriving labelled ChaCha children.
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const BLOCK_SIZE: usize = 64;
+
+/// The size in bytes of a SHA-256 digest.
+pub(crate) const DIGEST_SIZE: usize = 32;
+
+/// Incremental SHA-256 hasher.
+#[derive(Clone)]
+pub(crate) struct Sha256 {
+    state: [u32; 8],
+    block: [u8; BLOCK_SIZE],
+    filled: usize,
+    length: u64,
+}
+
+impl Sha256 {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            state: INITIAL_STATE,
+            block: [0; BLOCK_SIZE],
+            filled: 0,
+            length: 0,
+        }
+    }
+
+    /// Hashes the given input in one go.
+    #[cfg(any(
+        test,
+        all(
+            feature = "jitter",
+            any(
+                feature = "wyrand",
+                feature = "chacha",
+                feature = "xoshiro",
+                feature = "pcg"
+            )
+        )
+    ))]
+    #[inline]
+    pub(crate) fn digest(input: &[u8]) -> [u8; DIGEST_SIZE] {
+        let mut hasher = Self::new();
+
+        hasher.update(input);
+
+        hasher.finalize()
+    }
+
+    pub(crate) fn update(&mut self, mut input: &[u8]) {
+        self.length = self.length.wrapping_add(input.len() as u64);
+
+        while !input.is_empty() {
+            let fill = (BLOCK_SIZE - self.filled).min(input.len());
+            let (head, tail) = input.split_at(fill);
+
+            self.block[self.filled..self.filled + fill].copy_from_slice(head);
+            self.filled += fill;
+            input = tail;
+
+            if self.filled == BLOCK_SIZE {
+                compress(&mut self.state, &self.block);
+                self.filled = 0;
+            }
+        }
+    }
+
+    pub(crate) fn finalize(mut self) -> [u8; DIGEST_SIZE] {
+        let bits = self.length.wrapping_mul(8).to_be_bytes();
+
+        self.block[self.filled] = 0x80;
+        self.block[self.filled + 1..].fill(0);
+
+        if self.filled + 1 > BLOCK_SIZE - bits.len() {
+            compress(&mut self.state, &self.block);
+            self.block.fill(0);
+        }
+
+        self.block[BLOCK_SIZE - bits.len()..].copy_from_slice(&bits);
+        compress(&mut self.state, &self.block);
+
+        let mut output = [0u8; DIGEST_SIZE];
+
+        output
+            .chunks_exact_mut(4)
+            .zip(self.state.iter())
+            .for_each(|(chunk, word)| chunk.copy_from_slice(&word.to_be_bytes()));
+
+        output
+    }
+}
+
+fn compress(state: &mut [u32; 8], block: &[u8; BLOCK_SIZE]) {
+    let mut schedule = [0u32; 64];
+
+    schedule
+        .iter_mut()
+        .zip(block.chunks_exact(4))
+        .for_each(|(word, chunk)| {
+            *word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        });
+
+    for index in 16..64 {
+        let s0 = schedule[index - 15].rotate_right(7)
+            ^ schedule[index - 15].rotate_right(18)
+            ^ (schedule[index - 15] >> 3);
+        let s1 = schedule[index - 2].rotate_right(17)
+            ^ schedule[index - 2].rotate_right(19)
+            ^ (schedule[index - 2] >> 10);
+
+        schedule[index] = schedule[index - 16]
+            .wrapping_add(s0)
+            .wrapping_add(schedule[index - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+    for (&constant, &word) in ROUND_CONSTANTS.iter().zip(schedule.iter()) {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choice = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(choice)
+            .wrapping_add(constant)
+            .wrapping_add(word);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(majority);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+
+    state
+        .iter_mut()
+        .zip([a, b, c, d, e, f, g, h])
+        .for_each(|(word, value)| *word = word.wrapping_add(value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fips_180_vectors() {
+        assert_eq!(
+            Sha256::digest(b""),
+            [
+                0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+                0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+                0x78, 0x52, 0xb8, 0x55
+            ]
+        );
+        assert_eq!(
+            Sha256::digest(b"abc"),
+            [
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad
+            ]
+        );
+        assert_eq!(
+            Sha256::digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            [
+                0x24, 0x8d, 0x6a, 0x61, 0xd2, 0x06, 0x38, 0xb8, 0xe5, 0xc0, 0x26, 0x93, 0x0c, 0x3e,
+                0x60, 0x39, 0xa3, 0x3c, 0xe4, 0x59, 0x64, 0xff, 0x21, 0x67, 0xf6, 0xec, 0xed, 0xd4,
+                0x19, 0xdb, 0x06, 0xc1
+            ]
+        );
+    }
+
+    #[test]
+    fn incremental_updates() {
+        let input = [0x5au8; 200];
+
+        let mut hasher = Sha256::new();
+
+        input.chunks(7).for_each(|chunk| hasher.update(chunk));
+
+        assert_eq!(hasher.finalize(), Sha256::digest(&input));
+    }
+}
@@ -12,6 +12,9 @@ use crate::{Deserialize, Serialize};
 #[cfg(all(feature = "serialize", feature = "atomic"))]
 use crate::Visitor;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 /// Trait for implementing [`State`] to be used in `WyRand`.
 ///
 /// Those implementing [`State`] should also ensure to implement
@@ -67,6 +70,13 @@ impl Debug for CellState {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Drop for CellState {
+    fn drop(&mut self) {
+        self.0.get_mut().zeroize();
+    }
+}
+
 impl Clone for CellState {
     #[inline]
     fn clone(&self) -> Self {
@@ -119,6 +129,22 @@ impl State for AtomicState {
     fn set(&self, value: u64) {
         self.0.store(value, Ordering::SeqCst);
     }
+
+    /// Updates the state with a single `fetch_add`, so that concurrent
+    /// callers can never observe the same resulting value.
+    #[inline(always)]
+    fn update(&self, value: u64) -> u64 {
+        self.0
+            .fetch_add(value, Ordering::SeqCst)
+            .wrapping_add(value)
+    }
+}
+
+#[cfg(all(feature = "atomic", feature = "zeroize"))]
+impl Drop for AtomicState {
+    fn drop(&mut self) {
+        self.0.get_mut().zeroize();
+    }
 }
 
 #[cfg(feature = "atomic")]
@@ -232,6 +258,38 @@ mod tests {
         assert_eq!(format!("{state:?}"), "CellState");
     }
 
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn cell_state_zeroize_on_drop() {
+        use core::mem::MaybeUninit;
+
+        let mut state = MaybeUninit::new(CellState::with_seed(u64::MAX));
+
+        // SAFETY: The state is initialised, so it can be dropped in place. It
+        // is not used as a CellState instance afterwards.
+        unsafe { state.assume_init_drop() };
+
+        // SAFETY: CellState is a transparent wrapper over a u64, so its memory
+        // can be read as one, and remains initialised after the drop.
+        assert_eq!(unsafe { state.as_ptr().cast::<u64>().read() }, 0);
+    }
+
+    #[cfg(all(feature = "atomic", feature = "zeroize"))]
+    #[test]
+    fn atomic_state_zeroize_on_drop() {
+        use core::mem::MaybeUninit;
+
+        let mut state = MaybeUninit::new(AtomicState::with_seed(u64::MAX));
+
+        // SAFETY: The state is initialised, so it can be dropped in place. It
+        // is not used as an AtomicState instance afterwards.
+        unsafe { state.assume_init_drop() };
+
+        // SAFETY: AtomicState is a transparent wrapper over an AtomicU64, which
+        // has the same in-memory representation as a u64.
+        assert_eq!(unsafe { state.as_ptr().cast::<u64>().read() }, 0);
+    }
+
     #[cfg(feature = "atomic")]
     #[test]
     fn atomic_state() {
@@ -248,6 +306,34 @@ mod tests {
         assert_eq!(state.get(), 11);
     }
 
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn atomic_state_concurrent_updates() {
+        use std::{collections::BTreeSet, sync::Arc, thread};
+
+        let state = Arc::new(AtomicState::with_seed(0));
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let state = state.clone();
+
+                thread::spawn(move || (0..10_000).map(|_| state.update(3)).collect::<Vec<_>>())
+            })
+            .collect();
+
+        let values: BTreeSet<u64> = threads
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+
+        assert_eq!(
+            values.len(),
+            80_000,
+            "every update should yield a unique value"
+        );
+        assert_eq!(state.get(), 240_000);
+    }
+
     #[cfg(all(feature = "fmt", feature = "atomic"))]
     #[test]
     fn atomic_state_no_leaking_debug() {
@@ -0,0 +1,38 @@
+//! Shared test helpers for the thread-safe PRNGs.
+use std::{collections::BTreeSet, sync::Arc, thread};
+
+use crate::GenCore;
+
+/// Number of threads drawing from the shared generator at once.
+const THREADS: usize = 8;
+
+/// Number of outputs each thread draws from the shared generator.
+const DRAWS: usize = 10_000;
+
+/// Draws from `rng` on several threads at once and asserts that no two calls,
+/// on the same thread or not, ever received the same output.
+pub(crate) fn assert_no_duplicate_outputs_under_contention<R>(rng: R)
+where
+    R: GenCore + Send + Sync + 'static,
+{
+    let rng = Arc::new(rng);
+
+    let threads: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let rng = rng.clone();
+
+            thread::spawn(move || (0..DRAWS).map(|_| rng.gen_u64()).collect::<Vec<_>>())
+        })
+        .collect();
+
+    let outputs: BTreeSet<u64> = threads
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+
+    assert_eq!(
+        outputs.len(),
+        THREADS * DRAWS,
+        "concurrent callers should never receive the same output"
+    );
+}
@@ -41,12 +41,20 @@
 //! let values: Vec<_> = repeat_with(|| rand.f32()).take(10).collect();
 //! ```
 //!
+//! # Errors
+//!
+//! Methods that panic on invalid input, such as [`TurboRand::u64`] given an empty
+//! range, have `try_` prefixed counterparts returning an [`Error`] instead. Likewise,
+//! generators seeded from the thread-local generators provide `try_new` constructors
+//! which return [`Error::EntropyUnavailable`] rather than panicking when no entropy
+//! can be sampled.
+//!
 //! # Features
 //!
 //! The base crate will always export the [`TurboCore`], [`GenCore`],
-//! [`SeededCore`], [`TurboRand`], [`SecureCore`] and [`ForkableCore`] traits, and will do
-//! so when set as `default-features = false` in the Cargo.toml. By default,
-//! it will have `wyrand` feature enabled as the basic PRNG exposed.
+//! [`SeededCore`], [`TurboRand`], [`SecureCore`], [`ForkableCore`], [`LabelledForkCore`]
+//! and [`StreamableCore`] traits, and will do so when set as `default-features = false`
+//! in the Cargo.toml. By default, it will have `wyrand` feature enabled as the basic PRNG exposed.
 //!
 //! * **`alloc`** - Enables support for boxed [`TurboCore`] references, as well
 //!   as [`TurboRand`] methods that return [`Vec`] results.
@@ -54,11 +62,26 @@
 //!   & [`chacha_rng::ChaChaRng`].
 //! * **`std`** - Enables `std` features, such as `alloc` methods as well as
 //!   [`Default`] implementations for [`rng::Rng`] & [`chacha_rng::ChaChaRng`].
+//!   Also provides the [`entropy`] module, for registering a custom
+//!   [`EntropySource`] to seed the thread-local generators with. OS entropy is
+//!   health tested before being used as a seed. Also provides [`ReseedingRng`],
+//!   for periodically reseeding a generator from an [`EntropySource`].
+//!   Together with `wyrand`, provides [`thread_rng`], a handle borrowing the
+//!   thread-local [`rng::Rng`] without forking it, and free functions such as
+//!   [`u64()`] and [`shuffle`] which draw from it. With `chacha`, likewise provides
+//!   [`thread_chacha_rng`].
+//!   The [`global`] module provides a process-wide deterministic mode, set with
+//!   [`global::set_seed`] or the `TURBORAND_SEED` environment variable, under
+//!   which the thread-local generators derive their seeds from a global seed.
 //! * **`wyrand`** - Enables [`rng::Rng`], so to provide a
 //!   basic, non-threadsafe PRNG. Enabled by default. `no-std` compatible.
+//!   Also provides the [`stateless`] module, for random values computed directly
+//!   from a seed and a key.
 //! * **`atomic`** - Enables [`rng::AtomicRng`], so
 //!   to provide a thread-safe variation of [`rng::Rng`]. Enables `wyrand`
-//!   feature implicitly. **Note**, this is slower than [`rng::Rng`].
+//!   feature implicitly. **Note**, this is slower than [`rng::Rng`]. With `std`
+//!   enabled, it also provides [`rng::ShardedRng`], which spreads its state over
+//!   per-thread shards to avoid contention when shared across many cores.
 //! * **`rand`** - Provides [`compatibility::RandCompat`], which implements [`RngCore`]
 //!   so to allow for compatibility with `rand` ecosystem of crates
 //! * **`serialize`** - Enables [`Serialize`] and [`Deserialize`] derives on [`rng::Rng`],
@@ -67,6 +90,26 @@
 //! * **`chacha`** - Enables [`chacha_rng::ChaChaRng`] for providing a more cryptographically
 //!   secure source of Rng. Note, this will be slower than [`rng::Rng`] in
 //!   throughput, but will produce much higher quality randomness. `no-std` compatible.
+//!   Also provides [`chacha_rng::ChaCha12Rng`] and [`chacha_rng::ChaCha20Rng`] for when
+//!   a higher round count is required.
+//!   Together with the `atomic` feature, also provides [`chacha_rng::SyncChaChaRng`],
+//!   a thread-safe variant of [`chacha_rng::ChaChaRng`] that can be shared across threads.
+//! * **`xoshiro`** - Enables the generators in [`xoshiro_rng`], providing fast
+//!   Xoshiro256++, Xoshiro256** and Xoroshiro128++ PRNGs with `jump` and `long_jump`
+//!   methods for splitting into non-overlapping sequences. `no-std` compatible.
+//! * **`pcg`** - Enables [`pcg_rng::Pcg32Rng`] and [`pcg_rng::Pcg64Rng`], fast PCG
+//!   PRNGs with selectable streams. [`pcg_rng::Pcg64Rng`] reproduces the output of
+//!   NumPy's `PCG64DXSM` given the same seed words. `no-std` compatible.
+//! * **`zeroize`** - Wipes the internal state of [`rng::Rng`], [`rng::AtomicRng`] and
+//!   the [`chacha_rng`] generators from memory with non-elidable volatile writes
+//!   when they are dropped, so that key material and unread keystream do not
+//!   linger in freed memory.
+//! * **`jitter`** - Provides [`entropy::JitterEntropy`], an entropy source collecting
+//!   CPU execution timing jitter, and uses it in place of the hash-based fallback
+//!   for when the OS fails to provide entropy. Enables `std` feature implicitly.
+//! * **`conditioning`** - Provides the [`conditioning`] module, with a von Neumann
+//!   debiaser, a Toeplitz hash extractor and a hash derivation function for turning
+//!   raw, biased entropy into seeds. `no-std` compatible.
 #![warn(missing_docs)]
 #![forbid(clippy::undocumented_unsafe_blocks)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -76,29 +119,52 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(all(feature = "std", any(feature = "wyrand", feature = "chacha")))]
-use alloc::rc::Rc;
-
-#[cfg(all(feature = "fmt", any(feature = "wyrand", feature = "chacha")))]
+#[cfg(all(
+    feature = "fmt",
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
+))]
 use core::fmt::Debug;
 
 #[cfg(all(
     feature = "std",
     target_arch = "wasm32",
-    any(feature = "wyrand", feature = "chacha")
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
 ))]
 use instant::Instant;
 #[cfg(all(
     feature = "std",
     not(target_arch = "wasm32"),
-    any(feature = "wyrand", feature = "chacha")
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
 ))]
 use std::time::Instant;
 
 #[cfg(feature = "rand")]
 use rand_core::RngCore;
 
-#[cfg(all(feature = "serialize", any(feature = "chacha", feature = "wyrand")))]
+#[cfg(all(
+    feature = "serialize",
+    any(
+        feature = "chacha",
+        feature = "wyrand",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
+))]
 use serde::{Deserialize, Serialize};
 
 #[cfg(all(feature = "serialize", any(feature = "chacha", feature = "atomic")))]
@@ -115,17 +181,89 @@ pub mod chacha_rng;
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 pub mod compatibility;
-#[cfg(all(feature = "std", any(feature = "wyrand", feature = "chacha")))]
-mod entropy;
+#[cfg(feature = "conditioning")]
+#[cfg_attr(docsrs, doc(cfg(feature = "conditioning")))]
+pub mod conditioning;
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod entropy;
+mod error;
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod global;
 mod internal;
+#[cfg(feature = "pcg")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pcg")))]
+pub mod pcg_rng;
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
+))]
+mod reseeding;
 #[cfg(any(feature = "wyrand", feature = "atomic"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "wyrand", feature = "atomic"))))]
 pub mod rng;
+mod seed;
 mod source;
+#[cfg(feature = "wyrand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub mod stateless;
+mod streams;
+#[cfg(all(feature = "std", feature = "wyrand"))]
+mod thread;
 mod traits;
+#[cfg(feature = "xoshiro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xoshiro")))]
+pub mod xoshiro_rng;
+
+pub use error::Error;
+pub use seed::SeedSequence;
+pub use streams::RngStreams;
 
+#[cfg(all(feature = "std", feature = "chacha"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "chacha"))))]
+pub use chacha_rng::{thread_chacha_rng, ThreadChaChaRng};
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "wyrand",
+        feature = "chacha",
+        feature = "xoshiro",
+        feature = "pcg"
+    )
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use reseeding::ReseedingRng;
+#[cfg(all(feature = "std", feature = "wyrand"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "wyrand"))))]
+pub use rng::{thread_rng, ThreadRng};
+#[cfg(all(feature = "std", feature = "wyrand"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "wyrand"))))]
+pub use thread::*;
 pub use traits::{
-    Fillable, ForkableCore, GenCore, SecureCore, SeededCore, TurboCore, TurboKind, TurboRand,
+    EntropySource, Fillable, ForkableCore, GenCore, LabelledForkCore, SecureCore, SeedBytes,
+    SeededCore, StreamableCore, TurboCore, TurboKind, TurboRand,
 };
 
 pub mod prelude;
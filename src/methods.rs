@@ -11,7 +11,7 @@ macro_rules! gen_int_const {
 pub(crate) use gen_int_const;
 
 macro_rules! trait_range_int {
-    ($value:tt, $unsigned:tt, $bigger:ty, $source:ident, $doc:tt) => {
+    ($value:tt, $try_value:ident, $unsigned:tt, $bigger:ty, $source:ident, $doc:tt) => {
         #[doc = $doc]
         ///
         /// # Panics
@@ -19,8 +19,8 @@ macro_rules! trait_range_int {
         /// Panics if the range is empty or invalid.
         #[inline]
         fn $value(&self, bounds: impl RangeBounds<$value>) -> $value {
-            const BITS: $bigger = $value::BITS as $bigger;
-
+            // Excluded bounds saturate rather than being rejected, so that
+            // ranges such as `..0` keep returning the bound itself.
             let lower = match bounds.start_bound() {
                 Bound::Included(lower) => *lower,
                 Bound::Excluded(lower) => lower.saturating_add(1),
@@ -32,9 +32,37 @@ macro_rules! trait_range_int {
                 Bound::Unbounded => $value::MAX,
             };
 
-            assert!(lower <= upper, "Range should not be zero sized or invalid");
+            match self.$try_value(lower..=upper) {
+                Ok(value) => value,
+                Err(_) => panic!("Range should not be zero sized or invalid"),
+            }
+        }
+
+        #[doc = $doc]
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::InvalidRange`] if the range is empty or invalid.
+        #[inline]
+        fn $try_value(&self, bounds: impl RangeBounds<$value>) -> Result<$value, Error> {
+            const BITS: $bigger = $value::BITS as $bigger;
+
+            let lower = match bounds.start_bound() {
+                Bound::Included(lower) => *lower,
+                Bound::Excluded(lower) => lower.checked_add(1).ok_or(Error::InvalidRange)?,
+                Bound::Unbounded => $value::MIN,
+            };
+            let upper = match bounds.end_bound() {
+                Bound::Included(upper) => *upper,
+                Bound::Excluded(upper) => upper.checked_sub(1).ok_or(Error::InvalidRange)?,
+                Bound::Unbounded => $value::MAX,
+            };
+
+            if lower > upper {
+                return Err(Error::InvalidRange);
+            }
 
-            match (lower, upper) {
+            Ok(match (lower, upper) {
                 ($value::MIN, $value::MAX) => self.$source(),
                 (_, _) => {
                     let range = upper.wrapping_sub(lower).wrapping_add(1) as $unsigned;
@@ -52,7 +80,7 @@ macro_rules! trait_range_int {
                     let value = (high >> BITS) as $value;
                     lower.wrapping_add(value)
                 }
-            }
+            })
         }
     };
 }
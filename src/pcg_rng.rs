@@ -0,0 +1,344 @@
+//! Fast but **not** cryptographically secure PRNGs based on the [PCG](https://www.pcg-random.org/)
+//! family of generators.
+//!
+//! Both generators take a stream selector as part of their seed, which sets the
+//! LCG increment. Generators seeded with the same state but different streams
+//! yield different sequences.
+use crate::{
+    source::pcg::{Pcg32, Pcg64Dxsm},
+    ForkableCore, GenCore, LabelledForkCore, SeedSequence, SeededCore, TurboCore, TurboKind,
+};
+
+#[cfg(feature = "std")]
+use crate::{entropy::LocalRng, Error};
+
+#[cfg(feature = "fmt")]
+use crate::Debug;
+
+#[cfg(feature = "serialize")]
+use crate::{Deserialize, Serialize};
+
+/// A Random Number generator, powered by the `PCG32` (XSH-RR 64/32) algorithm.
+///
+/// The seed is a pair of `[state, stream]` words, matching the reference
+/// `pcg32_srandom_r(state, stream)` seeding.
+///
+/// # Example
+/// ```
+/// use turborand::prelude::*;
+///
+/// let rng = Pcg32Rng::with_seed([42, 54]);
+///
+/// assert_eq!(rng.gen_u32(), 0xa15c02b7);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(docsrs, doc(cfg(feature = "pcg")))]
+#[repr(transparent)]
+pub struct Pcg32Rng(Pcg32);
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Pcg32Rng {
+    /// Creates a new [`Pcg32Rng`] with a randomised seed.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        PCG32.with(|local| local.with(|rng| rng.fork()))
+    }
+
+    /// Creates a new [`Pcg32Rng`] with a randomised seed, or returns
+    /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+    /// be seeded.
+    #[inline]
+    pub fn try_new() -> Result<Self, Error> {
+        PCG32.with(|local| local.try_with(|rng| rng.fork()))
+    }
+
+    /// Reseeds the current thread-local generator.
+    #[inline]
+    pub fn reseed_local(seed: [u64; 2]) {
+        PCG32.with(|local| local.with(|rng| rng.reseed(seed)));
+    }
+}
+
+impl TurboCore for Pcg32Rng {
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        self.0.fill(buffer);
+    }
+}
+
+impl GenCore for Pcg32Rng {
+    const GEN_KIND: TurboKind = TurboKind::FAST;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        self.0.rand()
+    }
+}
+
+impl SeededCore for Pcg32Rng {
+    type Seed = [u64; 2];
+
+    #[inline]
+    fn with_seed([state, stream]: Self::Seed) -> Self {
+        Self(Pcg32::with_stream(state, stream))
+    }
+
+    #[inline]
+    fn reseed(&self, [state, stream]: Self::Seed) {
+        self.0.reseed(state, stream);
+    }
+}
+
+impl ForkableCore for Pcg32Rng {
+    #[inline]
+    fn fork(&self) -> Self {
+        let seed = self.0.rand::<16>();
+        let (state, stream) = seed.split_at(8);
+
+        Self::with_seed([
+            u64::from_le_bytes(state.try_into().unwrap()),
+            u64::from_le_bytes(stream.try_into().unwrap()),
+        ])
+    }
+}
+
+impl LabelledForkCore for Pcg32Rng {
+    #[inline]
+    fn fork_with_label(&self, label: &[u8]) -> Self {
+        Self::from_seed_sequence(SeedSequence::labelled(self.0.state_words(), label))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Default for Pcg32Rng {
+    /// Initialises a default instance of [`Pcg32Rng`]. Warning, the default is
+    /// seeded with a randomly generated state, so this is **not** deterministic.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = Pcg32Rng::default();
+    /// let rng2 = Pcg32Rng::default();
+    ///
+    /// assert_ne!(rng1.u64(..), rng2.u64(..));
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A Random Number generator, powered by the `PCG64 DXSM` algorithm.
+///
+/// The seed is four `u64` words, laid out as `[state_high, state_low,
+/// stream_high, stream_low]`. This is the same layout as NumPy's
+/// `SeedSequence.generate_state(4, np.uint64)` output which seeds
+/// `PCG64DXSM`, so the same seed words yield the same bit stream.
+///
+/// # Example
+/// ```
+/// use turborand::prelude::*;
+///
+/// let rng = Pcg64Rng::with_seed([0, 42, 0, 54]);
+///
+/// assert_eq!(rng.gen_u64(), 10070132190142401410);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "fmt", derive(Debug))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(docsrs, doc(cfg(feature = "pcg")))]
+#[repr(transparent)]
+pub struct Pcg64Rng(Pcg64Dxsm);
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Pcg64Rng {
+    /// Creates a new [`Pcg64Rng`] with a randomised seed.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        PCG64.with(|local| local.with(|rng| rng.fork()))
+    }
+
+    /// Creates a new [`Pcg64Rng`] with a randomised seed, or returns
+    /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+    /// be seeded.
+    #[inline]
+    pub fn try_new() -> Result<Self, Error> {
+        PCG64.with(|local| local.try_with(|rng| rng.fork()))
+    }
+
+    /// Reseeds the current thread-local generator.
+    #[inline]
+    pub fn reseed_local(seed: [u64; 4]) {
+        PCG64.with(|local| local.with(|rng| rng.reseed(seed)));
+    }
+}
+
+#[inline]
+fn join_words(high: u64, low: u64) -> u128 {
+    (u128::from(high) << 64) | u128::from(low)
+}
+
+impl TurboCore for Pcg64Rng {
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        self.0.fill(buffer);
+    }
+}
+
+impl GenCore for Pcg64Rng {
+    const GEN_KIND: TurboKind = TurboKind::FAST;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        self.0.rand()
+    }
+}
+
+impl SeededCore for Pcg64Rng {
+    type Seed = [u64; 4];
+
+    #[inline]
+    fn with_seed([state_high, state_low, stream_high, stream_low]: Self::Seed) -> Self {
+        Self(Pcg64Dxsm::with_stream(
+            join_words(state_high, state_low),
+            join_words(stream_high, stream_low),
+        ))
+    }
+
+    #[inline]
+    fn reseed(&self, [state_high, state_low, stream_high, stream_low]: Self::Seed) {
+        self.0.reseed(
+            join_words(state_high, state_low),
+            join_words(stream_high, stream_low),
+        );
+    }
+}
+
+impl ForkableCore for Pcg64Rng {
+    #[inline]
+    fn fork(&self) -> Self {
+        let state = u128::from_le_bytes(self.0.rand());
+        let stream = u128::from_le_bytes(self.0.rand());
+
+        Self(Pcg64Dxsm::with_stream(state, stream))
+    }
+}
+
+impl LabelledForkCore for Pcg64Rng {
+    #[inline]
+    fn fork_with_label(&self, label: &[u8]) -> Self {
+        Self::from_seed_sequence(SeedSequence::labelled(self.0.state_words(), label))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Default for Pcg64Rng {
+    /// Initialises a default instance of [`Pcg64Rng`]. Warning, the default is
+    /// seeded with a randomly generated state, so this is **not** deterministic.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = Pcg64Rng::default();
+    /// let rng2 = Pcg64Rng::default();
+    ///
+    /// assert_ne!(rng1.u64(..), rng2.u64(..));
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    static PCG32: LocalRng<Pcg32Rng> = const { LocalRng::new("PCG32") };
+    static PCG64: LocalRng<Pcg64Rng> = const { LocalRng::new("PCG64") };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "fmt", feature = "alloc"))]
+    #[test]
+    fn no_leaking_debug() {
+        #[cfg(all(feature = "alloc", not(feature = "std")))]
+        use alloc::format;
+
+        let rng = Pcg32Rng::with_seed([42, 54]);
+
+        assert_eq!(format!("{rng:?}"), "Pcg32Rng(Pcg32)");
+
+        let rng = Pcg64Rng::with_seed([0, 42, 0, 54]);
+
+        assert_eq!(format!("{rng:?}"), "Pcg64Rng(Pcg64Dxsm)");
+    }
+
+    #[test]
+    fn pcg64_seed_word_order() {
+        let rng = Pcg64Rng::with_seed([1, 2, 3, 4]);
+
+        let expected: [u64; 3] = [
+            12640754359219452034,
+            15916500817166435027,
+            8945810312355023763,
+        ];
+
+        for value in expected {
+            assert_eq!(rng.gen_u64(), value);
+        }
+    }
+
+    #[test]
+    fn forking() {
+        let rng1 = Pcg64Rng::with_seed([1, 2, 3, 4]);
+        let rng2 = Pcg64Rng::with_seed([1, 2, 3, 4]);
+
+        let forked1 = rng1.fork();
+        let forked2 = rng2.fork();
+
+        assert_ne!(forked1, rng1);
+        assert_eq!(forked1, forked2);
+        assert_eq!(forked1.gen_u64(), forked2.gen_u64());
+
+        let rng1 = Pcg32Rng::with_seed([42, 54]);
+        let rng2 = Pcg32Rng::with_seed([42, 54]);
+
+        assert_eq!(rng1.fork(), rng2.fork());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn pcg32_serde_tokens() {
+        use serde_test::{assert_tokens, Token};
+
+        let rng = Pcg32Rng::with_seed([42, 54]);
+
+        assert_tokens(
+            &rng,
+            &[
+                Token::NewtypeStruct { name: "Pcg32Rng" },
+                Token::Struct {
+                    name: "Pcg32",
+                    len: 2,
+                },
+                Token::BorrowedStr("state"),
+                Token::U64(1753877967969059832),
+                Token::BorrowedStr("increment"),
+                Token::U64(109),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
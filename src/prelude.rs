@@ -2,6 +2,8 @@
 
 pub use crate::traits::*;
 
+pub use crate::SeedSequence;
+
 #[cfg(any(feature = "wyrand", feature = "atomic"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "wyrand", feature = "atomic"))))]
 pub use crate::rng::*;
@@ -10,6 +12,14 @@ pub use crate::rng::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
 pub use crate::chacha_rng::*;
 
+#[cfg(feature = "pcg")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pcg")))]
+pub use crate::pcg_rng::*;
+
+#[cfg(feature = "xoshiro")]
+#[cfg_attr(docsrs, doc(cfg(feature = "xoshiro")))]
+pub use crate::xoshiro_rng::*;
+
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 pub use crate::compatibility::*;
@@ -0,0 +1,331 @@
+use std::{cell::Cell, time::Duration};
+
+use crate::{
+    entropy::OsEntropy, EntropySource, Error, GenCore, Instant, SecureCore, SeedBytes, SeededCore,
+    TurboCore, TurboKind,
+};
+
+#[cfg(feature = "fmt")]
+use crate::Debug;
+
+/// The default number of bytes generated between reseeds.
+const DEFAULT_THRESHOLD: u64 = 64 * 1024;
+
+/// Size of the chunks of inner output mixed into fresh entropy on reseeding.
+const MIX_CHUNK_SIZE: usize = 64;
+
+/// A wrapper that periodically reseeds a [`SeededCore`] generator from an
+/// [`EntropySource`], for long-lived generators that must be rekeyed
+/// regularly.
+///
+/// The inner generator is reseeded once a number of bytes have been generated
+/// since the last reseed, 64 KiB by default, once an optional time interval
+/// has elapsed, or on demand with [`ReseedingRng::reseed_now`]. With
+/// prediction resistance enabled, it is instead reseeded before every
+/// request, so that no output can be predicted even from a compromised state.
+///
+/// Fresh entropy is mixed with output of the inner generator to form the new
+/// seed, rather than replacing the state outright, so a reseed never makes
+/// the generator weaker than it was. Should a periodic reseed fail, the
+/// generator keeps running on its current state and retries on the next
+/// request.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use turborand::{entropy::OsEntropy, prelude::*, ReseedingRng};
+///
+/// let rng = ReseedingRng::new(Rng::new(), OsEntropy)
+///     .with_threshold(1024 * 1024)
+///     .with_interval(Duration::from_secs(60 * 60));
+///
+/// let value = rng.u64(..);
+///
+/// rng.reseed_now().expect("OS entropy should be available");
+/// ```
+#[cfg_attr(feature = "fmt", derive(Debug))]
+pub struct ReseedingRng<R: SeededCore, E: EntropySource = OsEntropy> {
+    inner: R,
+    source: E,
+    threshold: u64,
+    interval: Option<Duration>,
+    prediction_resistance: bool,
+    bytes_until_reseed: Cell<u64>,
+    last_reseed: Cell<Instant>,
+}
+
+impl<R: SeededCore, E: EntropySource> ReseedingRng<R, E>
+where
+    R::Seed: SeedBytes,
+{
+    /// Wraps an already seeded generator, to be reseeded from `source` after
+    /// every 64 KiB of output.
+    #[inline]
+    #[must_use]
+    pub fn new(inner: R, source: E) -> Self {
+        Self {
+            inner,
+            source,
+            threshold: DEFAULT_THRESHOLD,
+            interval: None,
+            prediction_resistance: false,
+            bytes_until_reseed: Cell::new(DEFAULT_THRESHOLD),
+            last_reseed: Cell::new(Instant::now()),
+        }
+    }
+
+    /// Sets the number of bytes to generate between reseeds. A threshold of
+    /// `0` disables reseeding based on the amount of output.
+    #[inline]
+    #[must_use]
+    pub fn with_threshold(self, threshold: u64) -> Self {
+        self.bytes_until_reseed.set(threshold);
+
+        Self { threshold, ..self }
+    }
+
+    /// Sets the time interval after which the generator is reseeded on the next
+    /// request, in addition to the byte threshold.
+    #[inline]
+    #[must_use]
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self {
+            interval: Some(interval),
+            ..self
+        }
+    }
+
+    /// Enables or disables prediction resistance, reseeding the generator with
+    /// fresh entropy before every request.
+    ///
+    /// # Panics
+    ///
+    /// With prediction resistance enabled, requests panic if the entropy
+    /// source fails, as their output could not be guaranteed to be fresh.
+    #[inline]
+    #[must_use]
+    pub fn with_prediction_resistance(self, prediction_resistance: bool) -> Self {
+        Self {
+            prediction_resistance,
+            ..self
+        }
+    }
+
+    /// Returns a reference to the inner generator.
+    #[inline]
+    #[must_use]
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Consumes the wrapper, returning the inner generator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reseeds the inner generator immediately, restarting the byte count and
+    /// interval until the next automatic reseed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`] of the entropy source if it fails, in which case
+    /// the inner generator is left on its current state.
+    pub fn reseed_now(&self) -> Result<(), Error> {
+        let mut result = Ok(());
+
+        let seed = R::Seed::from_fill(|buffer| {
+            result = self.source.try_fill_entropy(buffer);
+
+            if result.is_err() {
+                return;
+            }
+
+            let mut block = [0u8; MIX_CHUNK_SIZE];
+
+            buffer.chunks_mut(MIX_CHUNK_SIZE).for_each(|chunk| {
+                let block = &mut block[..chunk.len()];
+
+                self.inner.fill_bytes(block);
+
+                chunk
+                    .iter_mut()
+                    .zip(block.iter())
+                    .for_each(|(byte, mixed)| *byte ^= mixed);
+            });
+        });
+
+        result?;
+
+        self.inner.reseed(seed);
+        self.bytes_until_reseed.set(self.threshold);
+        self.last_reseed.set(Instant::now());
+
+        Ok(())
+    }
+
+    #[inline]
+    fn is_due(&self, amount: usize) -> bool {
+        (self.threshold != 0 && self.bytes_until_reseed.get() < amount as u64)
+            || self
+                .interval
+                .is_some_and(|interval| self.last_reseed.get().elapsed() >= interval)
+    }
+
+    /// Reseeds if required before generating `amount` bytes.
+    #[inline]
+    fn prepare(&self, amount: usize) {
+        if self.prediction_resistance {
+            self.reseed_now()
+                .expect("Entropy source should provide fresh entropy for prediction resistance");
+        } else if self.is_due(amount) {
+            // On failure, carry on with the current state and retry next time.
+            let _ = self.reseed_now();
+        }
+
+        self.bytes_until_reseed
+            .set(self.bytes_until_reseed.get().saturating_sub(amount as u64));
+    }
+}
+
+impl<R: SeededCore, E: EntropySource> TurboCore for ReseedingRng<R, E>
+where
+    R::Seed: SeedBytes,
+{
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        self.prepare(buffer.len());
+        self.inner.fill_bytes(buffer);
+    }
+}
+
+impl<R: SeededCore + GenCore, E: EntropySource> GenCore for ReseedingRng<R, E>
+where
+    R::Seed: SeedBytes,
+{
+    const GEN_KIND: TurboKind = R::GEN_KIND;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        self.prepare(SIZE);
+        self.inner.gen()
+    }
+}
+
+impl<R: SeededCore + SecureCore, E: EntropySource> SecureCore for ReseedingRng<R, E> where
+    R::Seed: SeedBytes
+{
+}
+
+#[cfg(all(test, any(feature = "wyrand", feature = "chacha")))]
+mod tests {
+    use super::*;
+
+    struct Counting(Cell<usize>);
+
+    impl EntropySource for Counting {
+        fn fill_entropy(&self, buffer: &mut [u8]) {
+            self.0.set(self.0.get() + 1);
+            buffer.fill(self.0.get() as u8);
+        }
+    }
+
+    struct Failing;
+
+    impl EntropySource for Failing {
+        fn fill_entropy(&self, _buffer: &mut [u8]) {
+            unreachable!()
+        }
+
+        fn try_fill_entropy(&self, _buffer: &mut [u8]) -> Result<(), Error> {
+            Err(Error::EntropyUnavailable)
+        }
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[test]
+    fn reseeds_after_threshold() {
+        use crate::rng::Rng;
+
+        let rng = ReseedingRng::new(Rng::with_seed(1), Counting(Cell::new(0))).with_threshold(16);
+
+        rng.gen_u64();
+        rng.gen_u64();
+
+        assert_eq!(rng.source.0.get(), 0);
+
+        rng.gen_u64();
+
+        assert_eq!(rng.source.0.get(), 1);
+
+        let mut buffer = [0u8; 20];
+
+        rng.fill_bytes(&mut buffer);
+
+        assert_eq!(rng.source.0.get(), 2);
+
+        let unlimited = ReseedingRng::new(Rng::with_seed(1), Counting(Cell::new(0)))
+            .with_threshold(0)
+            .with_interval(Duration::from_secs(3600));
+
+        (0..100).for_each(|_| {
+            unlimited.gen_u64();
+        });
+
+        assert_eq!(unlimited.source.0.get(), 0);
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[test]
+    fn reseed_mixes_inner_state() {
+        use crate::rng::Rng;
+
+        let rng1 = ReseedingRng::new(Rng::with_seed(1), Counting(Cell::new(0)));
+        let rng2 = ReseedingRng::new(Rng::with_seed(2), Counting(Cell::new(0)));
+
+        rng1.reseed_now().unwrap();
+        rng2.reseed_now().unwrap();
+
+        assert_ne!(rng1.inner(), rng2.inner());
+
+        let failing = ReseedingRng::new(Rng::with_seed(1), Failing);
+        let expected = Rng::with_seed(1);
+
+        assert_eq!(failing.reseed_now(), Err(Error::EntropyUnavailable));
+        assert_eq!(failing.inner(), &expected);
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn prediction_resistance_reseeds_every_request() {
+        use crate::{chacha_rng::ChaChaRng, TurboRand};
+
+        let rng = ReseedingRng::new(ChaChaRng::with_seed([0; 40]), Counting(Cell::new(0)))
+            .with_prediction_resistance(true);
+
+        rng.bool();
+        rng.u64(..);
+
+        assert_eq!(rng.source.0.get(), 2);
+
+        let interval = ReseedingRng::new(ChaChaRng::with_seed([0; 40]), Counting(Cell::new(0)))
+            .with_interval(Duration::ZERO);
+
+        interval.bool();
+
+        assert_eq!(interval.source.0.get(), 1);
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    #[should_panic(expected = "fresh entropy")]
+    fn prediction_resistance_requires_entropy() {
+        use crate::{chacha_rng::ChaChaRng, TurboRand};
+
+        let rng = ReseedingRng::new(ChaChaRng::with_seed([0; 40]), Failing)
+            .with_prediction_resistance(true);
+
+        rng.bool();
+    }
+}
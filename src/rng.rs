@@ -1,12 +1,12 @@
 //! A fast but **not** cryptographically secure PRNG based on [Wyrand](https://github.com/wangyi-fudan/wyhash).
 
 use crate::{
-    internal::state::CellState, source::wyrand::WyRand, ForkableCore, GenCore, SeededCore,
-    TurboCore, TurboKind,
+    internal::state::CellState, source::wyrand::WyRand, Error, ForkableCore, GenCore,
+    LabelledForkCore, SeedSequence, SeededCore, StreamableCore, TurboCore, TurboKind,
 };
 
 #[cfg(feature = "std")]
-use crate::{entropy::generate_entropy, Rc};
+use crate::entropy::{restoring, LocalRng};
 
 #[cfg(feature = "fmt")]
 use crate::Debug;
@@ -14,9 +14,40 @@ use crate::Debug;
 #[cfg(feature = "atomic")]
 use crate::internal::state::AtomicState;
 
+#[cfg(all(feature = "std", feature = "atomic"))]
+use crate::internal::padded::CachePadded;
+
 #[cfg(feature = "serialize")]
 use crate::{Deserialize, Serialize};
 
+/// Number of outputs given to each stream of a [`StreamableCore`] `WyRand`
+/// generator, as a power of two.
+const STREAM_LENGTH_BITS: u32 = 40;
+
+/// Returns the number of outputs to advance by to reach stream `index`, with
+/// the 2^64 period split into 2^24 streams of 2^40 outputs each.
+#[inline]
+fn stream_offset(index: u64) -> Result<u64, Error> {
+    if index >> (u64::BITS - STREAM_LENGTH_BITS) != 0 {
+        return Err(Error::InvalidRange);
+    }
+
+    Ok(index << STREAM_LENGTH_BITS)
+}
+
+/// An opaque copy of the state of an [`Rng`] or `AtomicRng`, for cheaply
+/// checkpointing a generator and rolling it back later. Snapshots are
+/// interchangeable between the two generators.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RngSnapshot(u64);
+
+#[cfg(feature = "fmt")]
+impl Debug for RngSnapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RngSnapshot").finish()
+    }
+}
+
 /// A Random Number generator, powered by the `WyRand` algorithm.
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "fmt", derive(Debug))]
@@ -32,13 +63,155 @@ impl Rng {
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        RNG.with(|rng| rng.fork())
+        RNG.with(|local| local.with(|rng| rng.fork()))
+    }
+
+    /// Creates a new [`Rng`] with a randomised seed, or returns
+    /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+    /// be seeded.
+    #[inline]
+    pub fn try_new() -> Result<Self, Error> {
+        RNG.with(|local| local.try_with(|rng| rng.fork()))
     }
 
     /// Reseeds the current thread-local generator.
     #[inline]
     pub fn reseed_local(seed: u64) {
-        RNG.with(|rng| rng.reseed(seed));
+        RNG.with(|local| local.with(|rng| rng.reseed(seed)));
+    }
+
+    /// Runs the closure with the current thread-local generator seeded with
+    /// `seed`, as if by [`Rng::with_seed`], so that every [`Rng::new`] within
+    /// it forks deterministically. The previous thread-local state is restored
+    /// afterwards, even if the closure panics.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let value = Rng::with_local_seed(42, || Rng::new().u64(..));
+    ///
+    /// assert_eq!(value, Rng::with_seed(42).fork().u64(..));
+    /// assert_eq!(Rng::with_local_seed(42, || Rng::new().u64(..)), value);
+    /// ```
+    #[inline]
+    pub fn with_local_seed<R>(seed: u64, f: impl FnOnce() -> R) -> R {
+        let previous = Self::local_snapshot();
+
+        Self::restore_local(Self::with_seed(seed).snapshot());
+
+        restoring(|| Self::restore_local(previous), f)
+    }
+
+    /// Returns a snapshot of the current thread-local generator, which can be
+    /// passed to [`Rng::restore_local`] to rewind it to this point.
+    #[inline]
+    #[must_use]
+    pub fn local_snapshot() -> RngSnapshot {
+        RNG.with(|local| local.with(|rng| rng.snapshot()))
+    }
+
+    /// Restores the current thread-local generator to the state captured by
+    /// [`Rng::local_snapshot`].
+    #[inline]
+    pub fn restore_local(snapshot: RngSnapshot) {
+        RNG.with(|local| local.with(|rng| rng.restore(snapshot)));
+    }
+}
+
+impl Rng {
+    /// Advances the generator by `steps` outputs in constant time, as if
+    /// `steps` `u64` values had been generated and discarded.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = Rng::with_seed(Default::default());
+    /// let rng2 = Rng::with_seed(Default::default());
+    ///
+    /// for _ in 0..5 {
+    ///     rng1.gen_u64();
+    /// }
+    ///
+    /// rng2.advance(5);
+    ///
+    /// assert_eq!(rng1.gen_u64(), rng2.gen_u64());
+    /// ```
+    #[inline]
+    pub fn advance(&self, steps: u64) {
+        self.0.advance(steps);
+    }
+
+    /// Rewinds the generator by `steps` outputs in constant time, so that
+    /// previously generated values are yielded again.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng = Rng::with_seed(Default::default());
+    ///
+    /// let value = rng.gen_u64();
+    ///
+    /// rng.rewind(1);
+    ///
+    /// assert_eq!(rng.gen_u64(), value);
+    /// ```
+    #[inline]
+    pub fn rewind(&self, steps: u64) {
+        self.0.rewind(steps);
+    }
+
+    /// Returns how many outputs `self` needs to [`Rng::advance`] by in order to
+    /// be at the same position as `other`. As the sequence has a period of
+    /// 2^64, the distance is given modulo 2^64, so an `other` that is behind
+    /// `self` by `n` outputs will yield `n.wrapping_neg()`.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = Rng::with_seed(Default::default());
+    /// let rng2 = Rng::with_seed(Default::default());
+    ///
+    /// rng2.advance(1_000_000_000);
+    ///
+    /// assert_eq!(rng1.position_distance(&rng2), 1_000_000_000);
+    /// assert_eq!(rng2.position_distance(&rng1), 1_000_000_000u64.wrapping_neg());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn position_distance(&self, other: &Self) -> u64 {
+        self.0.distance(&other.0)
+    }
+
+    /// Returns a snapshot of the current state, which can be passed to
+    /// [`Rng::restore`] to roll the generator back to this point.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng = Rng::with_seed(Default::default());
+    ///
+    /// let checkpoint = rng.snapshot();
+    /// let value = rng.u64(..);
+    ///
+    /// rng.restore(checkpoint);
+    ///
+    /// assert_eq!(rng.u64(..), value);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn snapshot(&self) -> RngSnapshot {
+        RngSnapshot(self.0.state())
+    }
+
+    /// Restores the generator to the state captured by [`Rng::snapshot`].
+    #[inline]
+    pub fn restore(&self, snapshot: RngSnapshot) {
+        self.0.reseed(snapshot.0);
     }
 }
 
@@ -62,6 +235,7 @@ impl SeededCore for Rng {
     type Seed = u64;
 
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn with_seed(seed: Self::Seed) -> Self {
         Self(WyRand::with_seed(seed << 1 | 1))
@@ -96,14 +270,40 @@ impl Default for Rng {
 
 impl ForkableCore for Rng {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn fork(&self) -> Self {
         Self(WyRand::with_seed(u64::from_le_bytes(self.0.rand())))
     }
 }
 
+impl LabelledForkCore for Rng {
+    #[inline]
+    fn fork_with_label(&self, label: &[u8]) -> Self {
+        Self::from_seed_sequence(SeedSequence::labelled(self.0.state_words(), label))
+    }
+}
+
+impl StreamableCore for Rng {
+    /// Creates the generator for stream `index` of the given seed, by advancing
+    /// past the earlier streams. The period of 2^64 outputs is split into 2^24
+    /// streams of 2^40 outputs each, so `index` must be less than 2^24.
+    #[inline]
+    fn try_with_stream(seed: Self::Seed, index: u64) -> Result<Self, Error> {
+        let rng = Self::with_seed(seed);
+
+        rng.advance(stream_offset(index)?);
+
+        Ok(rng)
+    }
+}
+
 /// A Random Number generator, powered by the `WyRand` algorithm, but with
 /// thread-safe internal state.
+///
+/// The state is advanced with a single atomic `fetch_add`, so concurrent
+/// callers sharing an [`AtomicRng`] each step to a distinct state and will
+/// never be handed the same output from the same step.
 #[cfg(feature = "atomic")]
 #[derive(PartialEq, Eq)]
 #[cfg_attr(feature = "fmt", derive(Debug))]
@@ -119,7 +319,18 @@ impl AtomicRng {
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(WyRand::with_seed(RNG.with(|rng| rng.gen_u64())))
+        Self(WyRand::with_seed(
+            RNG.with(|local| local.with(|rng| rng.gen_u64())),
+        ))
+    }
+
+    /// Creates a new [`AtomicRng`] with a randomised seed, or returns
+    /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+    /// be seeded.
+    #[inline]
+    pub fn try_new() -> Result<Self, Error> {
+        RNG.with(|local| local.try_with(|rng| rng.gen_u64()))
+            .map(|seed| Self(WyRand::with_seed(seed)))
     }
 }
 
@@ -144,6 +355,103 @@ impl Default for AtomicRng {
     }
 }
 
+#[cfg(feature = "atomic")]
+impl AtomicRng {
+    /// Advances the generator by `steps` outputs in constant time, as if
+    /// `steps` `u64` values had been generated and discarded.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = AtomicRng::with_seed(Default::default());
+    /// let rng2 = AtomicRng::with_seed(Default::default());
+    ///
+    /// for _ in 0..5 {
+    ///     rng1.gen_u64();
+    /// }
+    ///
+    /// rng2.advance(5);
+    ///
+    /// assert_eq!(rng1.gen_u64(), rng2.gen_u64());
+    /// ```
+    #[inline]
+    pub fn advance(&self, steps: u64) {
+        self.0.advance(steps);
+    }
+
+    /// Rewinds the generator by `steps` outputs in constant time, so that
+    /// previously generated values are yielded again.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng = AtomicRng::with_seed(Default::default());
+    ///
+    /// let value = rng.gen_u64();
+    ///
+    /// rng.rewind(1);
+    ///
+    /// assert_eq!(rng.gen_u64(), value);
+    /// ```
+    #[inline]
+    pub fn rewind(&self, steps: u64) {
+        self.0.rewind(steps);
+    }
+
+    /// Returns how many outputs `self` needs to [`AtomicRng::advance`] by in order to
+    /// be at the same position as `other`. As the sequence has a period of
+    /// 2^64, the distance is given modulo 2^64, so an `other` that is behind
+    /// `self` by `n` outputs will yield `n.wrapping_neg()`.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = AtomicRng::with_seed(Default::default());
+    /// let rng2 = AtomicRng::with_seed(Default::default());
+    ///
+    /// rng2.advance(1_000_000_000);
+    ///
+    /// assert_eq!(rng1.position_distance(&rng2), 1_000_000_000);
+    /// assert_eq!(rng2.position_distance(&rng1), 1_000_000_000u64.wrapping_neg());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn position_distance(&self, other: &Self) -> u64 {
+        self.0.distance(&other.0)
+    }
+
+    /// Returns a snapshot of the current state, which can be passed to
+    /// [`AtomicRng::restore`] to roll the generator back to this point.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng = AtomicRng::with_seed(Default::default());
+    ///
+    /// let checkpoint = rng.snapshot();
+    /// let value = rng.u64(..);
+    ///
+    /// rng.restore(checkpoint);
+    ///
+    /// assert_eq!(rng.u64(..), value);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn snapshot(&self) -> RngSnapshot {
+        RngSnapshot(self.0.state())
+    }
+
+    /// Restores the generator to the state captured by [`AtomicRng::snapshot`].
+    #[inline]
+    pub fn restore(&self, snapshot: RngSnapshot) {
+        self.0.reseed(snapshot.0);
+    }
+}
+
 #[cfg(feature = "atomic")]
 impl Clone for AtomicRng {
     /// Clones the [`AtomicRng`] by deterministically deriving a new [`AtomicRng`] based on the initial
@@ -173,12 +481,36 @@ impl Clone for AtomicRng {
 #[cfg(feature = "atomic")]
 impl ForkableCore for AtomicRng {
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn fork(&self) -> Self {
         Self(WyRand::with_seed(u64::from_le_bytes(self.0.rand())))
     }
 }
 
+#[cfg(feature = "atomic")]
+impl LabelledForkCore for AtomicRng {
+    #[inline]
+    fn fork_with_label(&self, label: &[u8]) -> Self {
+        Self::from_seed_sequence(SeedSequence::labelled(self.0.state_words(), label))
+    }
+}
+
+#[cfg(feature = "atomic")]
+impl StreamableCore for AtomicRng {
+    /// Creates the generator for stream `index` of the given seed, by advancing
+    /// past the earlier streams. The period of 2^64 outputs is split into 2^24
+    /// streams of 2^40 outputs each, so `index` must be less than 2^24.
+    #[inline]
+    fn try_with_stream(seed: Self::Seed, index: u64) -> Result<Self, Error> {
+        let rng = Self::with_seed(seed);
+
+        rng.advance(stream_offset(index)?);
+
+        Ok(rng)
+    }
+}
+
 #[cfg(feature = "atomic")]
 impl TurboCore for AtomicRng {
     #[inline]
@@ -202,6 +534,7 @@ impl SeededCore for AtomicRng {
     type Seed = u64;
 
     #[inline]
+    #[allow(unused_attributes)]
     #[must_use]
     fn with_seed(seed: Self::Seed) -> Self {
         Self(WyRand::with_seed(seed << 1 | 1))
@@ -213,11 +546,292 @@ impl SeededCore for AtomicRng {
     }
 }
 
+/// A thread-safe Random Number generator, powered by the `WyRand` algorithm,
+/// which spreads its state over a number of cache-line padded shards.
+///
+/// Threads are assigned shards in round-robin order the first time they use
+/// a [`ShardedRng`], so up to as many threads as there are shards each
+/// advance their own state instead of contending over a single one like with
+/// [`AtomicRng`]. Shards are still atomic, so threads that share a shard
+/// remain correct, only slower. As the shard used depends on the calling
+/// thread, the output of a [`ShardedRng`] is only deterministic when used from
+/// a single thread.
+///
+/// # Example
+/// ```
+/// use turborand::prelude::*;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let rand = Arc::new(ShardedRng::with_seed(Default::default()));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let rand = rand.clone();
+///
+///         thread::spawn(move || rand.u64(..))
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+#[cfg(all(feature = "std", feature = "atomic"))]
+#[derive(PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "atomic"))))]
+pub struct ShardedRng {
+    shards: Box<[CachePadded<WyRand<AtomicState>>]>,
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl ShardedRng {
+    /// Number of shards used by [`SeededCore::with_seed`], fixed so that
+    /// seeded output does not depend on the host machine.
+    pub const DEFAULT_SHARDS: usize = 16;
+
+    /// Creates a new [`ShardedRng`] with a randomised seed and a shard for each
+    /// available core.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_shards(
+            RNG.with(|local| local.with(|rng| rng.gen_u64())),
+            Self::default_shards(),
+        )
+    }
+
+    /// Creates a new [`ShardedRng`] with a randomised seed, or returns
+    /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+    /// be seeded.
+    #[inline]
+    pub fn try_new() -> Result<Self, Error> {
+        RNG.with(|local| local.try_with(|rng| rng.gen_u64()))
+            .map(|seed| Self::with_shards(seed, Self::default_shards()))
+    }
+
+    /// Creates a new [`ShardedRng`] with the given seed and number of shards. The
+    /// number of shards is rounded up to the next power of two, with a minimum
+    /// of one shard. Each shard is seeded with a value derived from `seed`.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = ShardedRng::with_shards(Default::default(), 8);
+    /// let rng2 = ShardedRng::with_shards(Default::default(), 8);
+    ///
+    /// assert_eq!(rng1.shards(), 8);
+    /// assert_eq!(rng1.u64(..), rng2.u64(..));
+    /// ```
+    #[must_use]
+    pub fn with_shards(seed: u64, shards: usize) -> Self {
+        let seeder = Rng::with_seed(seed);
+
+        Self {
+            shards: (0..shards.max(1).next_power_of_two())
+                .map(|_| CachePadded::new(WyRand::with_seed(seeder.gen_u64())))
+                .collect(),
+        }
+    }
+
+    /// Returns the number of shards the state is spread over.
+    #[inline]
+    #[must_use]
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    #[inline]
+    fn default_shards() -> usize {
+        std::thread::available_parallelism().map_or(1, usize::from)
+    }
+
+    #[inline]
+    fn shard(&self) -> &WyRand<AtomicState> {
+        let index = THREAD_SHARD.with(|shard| *shard) & (self.shards.len() - 1);
+
+        &self.shards[index]
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl Clone for ShardedRng {
+    /// Clones the [`ShardedRng`] by forking a new [`ShardedRng`] with the same
+    /// number of shards, so the clone does not repeat the output of the
+    /// original.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = ShardedRng::with_shards(Default::default(), 4);
+    /// let rng2 = ShardedRng::with_shards(Default::default(), 4);
+    ///
+    /// let cloned1 = rng1.clone();
+    /// let cloned2 = rng2.clone();
+    ///
+    /// assert_eq!(cloned1.shards(), 4);
+    /// assert_eq!(cloned1.u64(..), cloned2.u64(..));
+    /// assert_ne!(cloned1.u64(..), rng1.u64(..));
+    /// ```
+    #[inline]
+    fn clone(&self) -> Self {
+        self.fork()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl Default for ShardedRng {
+    /// Initialises a default instance of [`ShardedRng`]. Warning, the default is
+    /// seeded with a randomly generated state, so this is **not** deterministic.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = ShardedRng::default();
+    /// let rng2 = ShardedRng::default();
+    ///
+    /// assert_ne!(rng1.u64(..), rng2.u64(..));
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic", feature = "fmt"))]
+impl Debug for ShardedRng {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ShardedRng")
+            .field("shards", &self.shards.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl TurboCore for ShardedRng {
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        self.shard().fill(buffer);
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl GenCore for ShardedRng {
+    const GEN_KIND: TurboKind = TurboKind::FAST;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        self.shard().rand()
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl SeededCore for ShardedRng {
+    type Seed = u64;
+
+    /// Creates a new [`ShardedRng`] with [`ShardedRng::DEFAULT_SHARDS`] shards.
+    /// Use [`ShardedRng::with_shards`] for a different number of shards.
+    #[inline]
+    fn with_seed(seed: Self::Seed) -> Self {
+        Self::with_shards(seed, Self::DEFAULT_SHARDS)
+    }
+
+    #[inline]
+    fn reseed(&self, seed: Self::Seed) {
+        let seeder = Rng::with_seed(seed);
+
+        self.shards
+            .iter()
+            .for_each(|shard| shard.reseed(seeder.gen_u64()));
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl ForkableCore for ShardedRng {
+    #[inline]
+    fn fork(&self) -> Self {
+        Self::with_shards(self.gen_u64(), self.shards())
+    }
+}
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+impl LabelledForkCore for ShardedRng {
+    #[inline]
+    fn fork_with_label(&self, label: &[u8]) -> Self {
+        let state = self.shards.iter().flat_map(|shard| shard.state_words());
+
+        Self::with_shards(SeedSequence::labelled(state, label).seed(), self.shards())
+    }
+}
+
+/// Counter handing out shard indices to threads in round-robin order.
+#[cfg(all(feature = "std", feature = "atomic"))]
+static NEXT_SHARD: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
+#[cfg(all(feature = "std", feature = "atomic"))]
+thread_local! {
+    static THREAD_SHARD: usize = NEXT_SHARD.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+}
+
 #[cfg(feature = "std")]
 thread_local! {
-    static RNG: Rc<Rng> = Rc::new(Rng(WyRand::with_seed(
-        u64::from_ne_bytes(generate_entropy::<{ core::mem::size_of::<u64>() }>()),
-    )));
+    static RNG: LocalRng<Rng> = const { LocalRng::new("RNG") };
+}
+
+/// A handle to the thread-local [`Rng`], which draws from it directly rather
+/// than forking a new generator as [`Rng::new`] does. The handle holds no
+/// state of its own, so it is free to create, and always draws from the
+/// generator of the thread it is used on.
+///
+/// # Panics
+///
+/// Generating values panics if the thread-local generator has yet to be
+/// seeded and no entropy is available.
+///
+/// # Example
+/// ```
+/// use turborand::prelude::*;
+///
+/// let rng = thread_rng();
+///
+/// let value = rng.u64(1..=6);
+///
+/// assert!((1..=6).contains(&value));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(feature = "fmt", derive(Debug))]
+pub struct ThreadRng;
+
+/// Returns a [`ThreadRng`] handle to the thread-local [`Rng`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[inline]
+#[must_use]
+pub fn thread_rng() -> ThreadRng {
+    ThreadRng
+}
+
+#[cfg(feature = "std")]
+impl TurboCore for ThreadRng {
+    #[inline]
+    fn fill_bytes(&self, buffer: &mut [u8]) {
+        RNG.with(|local| local.with(|rng| rng.fill_bytes(buffer)));
+    }
+}
+
+#[cfg(feature = "std")]
+impl GenCore for ThreadRng {
+    const GEN_KIND: TurboKind = Rng::GEN_KIND;
+
+    #[inline]
+    fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+        RNG.with(|local| local.with(|rng| rng.gen()))
+    }
 }
 
 #[cfg(test)]
@@ -236,6 +850,10 @@ mod tests {
         let rng = Rng::with_seed(Default::default());
 
         assert_eq!(format!("{rng:?}"), "Rng(WyRand(CellState))");
+
+        let snapshot = rng.snapshot();
+
+        assert_eq!(format!("{snapshot:?}"), "RngSnapshot");
     }
 
     #[cfg(all(feature = "fmt", feature = "atomic"))]
@@ -246,6 +864,222 @@ mod tests {
         assert_eq!(format!("{rng:?}"), "AtomicRng(WyRand(AtomicState))");
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn try_new_forks_thread_local() {
+        let rng1 = Rng::try_new().unwrap();
+        let rng2 = Rng::try_new().unwrap();
+
+        assert_ne!(rng1, rng2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn thread_rng_borrows_thread_local() {
+        let expected = Rng::with_seed(Default::default());
+
+        expected.reseed(5);
+        Rng::reseed_local(5);
+
+        assert_eq!(thread_rng().gen_u64(), expected.gen_u64());
+        assert_eq!(thread_rng().gen::<3>(), expected.gen::<3>());
+        assert_eq!(Rng::new(), expected.fork());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn local_seed_is_scoped() {
+        let before = Rng::local_snapshot();
+
+        let (forked, inner) = Rng::with_local_seed(3, || (Rng::new(), Rng::local_snapshot()));
+
+        assert_eq!(forked, Rng::with_seed(3).fork());
+        assert_ne!(inner, before);
+        assert_eq!(Rng::local_snapshot(), before);
+
+        let panicked = std::panic::catch_unwind(|| {
+            Rng::with_local_seed(3, || panic!("closure should unwind"));
+        });
+
+        assert!(panicked.is_err());
+        assert_eq!(Rng::local_snapshot(), before);
+    }
+
+    #[test]
+    fn labelled_forks_are_order_independent() {
+        let world1 = Rng::with_seed(42);
+        let world2 = Rng::with_seed(42);
+
+        let audio = world1.fork_with_label(b"audio");
+        let physics1 = world1.fork_with_label(b"physics");
+        let physics2 = world2.derive_child("physics");
+
+        assert_eq!(physics1, physics2);
+        assert_ne!(audio, physics1);
+        assert_eq!(world1, world2);
+
+        world1.gen_u64();
+
+        assert_ne!(world1.fork_with_label(b"physics"), physics1);
+
+        // Plain forks advance the parent, so they change labelled children too.
+        world2.fork();
+
+        assert_ne!(world2.fork_with_label(b"physics"), physics1);
+        assert_eq!(
+            world1.fork_with_label(b"physics"),
+            world2.derive_child("physics")
+        );
+    }
+
+    #[test]
+    fn streams_are_evenly_spaced() {
+        let streams = crate::RngStreams::<Rng>::new(42);
+
+        let stream0 = streams.stream(0);
+        let stream3 = streams.stream(3);
+
+        assert_eq!(stream0, Rng::with_seed(42));
+        assert_eq!(stream0.position_distance(&stream3), 3 << 40);
+        assert_eq!(
+            streams
+                .try_stream((1 << 24) - 1)
+                .map(|rng| stream0.position_distance(&rng)),
+            Ok(0xffff_ff00_0000_0000)
+        );
+        assert_eq!(streams.try_stream(1 << 24), Err(Error::InvalidRange));
+    }
+
+    #[test]
+    fn rng_advance_skips_outputs() {
+        let rng1 = Rng::with_seed(42);
+        let rng2 = Rng::with_seed(42);
+
+        let skipped: [u64; 4] = core::array::from_fn(|_| rng1.gen_u64());
+
+        rng2.advance(3);
+
+        assert_eq!(rng2.gen_u64(), skipped[3]);
+        assert_eq!(rng1, rng2);
+
+        rng2.rewind(4);
+
+        assert_eq!(rng2.gen_u64(), skipped[0]);
+        assert_eq!(rng2.position_distance(&rng1), 3);
+    }
+
+    #[cfg(feature = "atomic")]
+    #[test]
+    fn atomic_advance_skips_outputs() {
+        let rng1 = AtomicRng::with_seed(42);
+        let rng2 = AtomicRng::with_seed(42);
+
+        rng2.advance(5_000_000_000);
+
+        assert_eq!(rng1.position_distance(&rng2), 5_000_000_000);
+
+        rng2.rewind(5_000_000_000);
+
+        assert_eq!(rng1, rng2);
+    }
+
+    #[cfg(feature = "atomic")]
+    #[test]
+    fn snapshots_are_interchangeable() {
+        let rng = Rng::with_seed(42);
+        let atomic = AtomicRng::with_seed(7);
+
+        rng.gen_u64();
+
+        let checkpoint = rng.snapshot();
+        let expected: [u64; 4] = core::array::from_fn(|_| rng.gen_u64());
+
+        atomic.restore(checkpoint);
+
+        assert_eq!(atomic.snapshot(), checkpoint);
+        assert_eq!(expected, core::array::from_fn(|_| atomic.gen_u64()));
+        assert_eq!(atomic.snapshot(), rng.snapshot());
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn atomic_no_duplicate_outputs_under_contention() {
+        crate::internal::testing::assert_no_duplicate_outputs_under_contention(
+            AtomicRng::with_seed(Default::default()),
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn sharded_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<ShardedRng>();
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn sharded_shard_count() {
+        assert_eq!(ShardedRng::with_shards(1, 0).shards(), 1);
+        assert_eq!(ShardedRng::with_shards(1, 5).shards(), 8);
+        assert_eq!(ShardedRng::with_shards(1, 64).shards(), 64);
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn sharded_seeding_and_reseeding() {
+        let rng1 = ShardedRng::with_shards(7, 4);
+        let rng2 = ShardedRng::with_shards(7, 4);
+
+        assert_eq!(rng1, rng2);
+
+        let value = rng1.gen_u64();
+
+        assert_eq!(value, rng2.gen_u64());
+
+        rng1.reseed(7);
+        rng2.reseed(7);
+
+        assert_eq!(rng1.gen_u64(), rng2.gen_u64());
+        assert_ne!(rng1, ShardedRng::with_shards(8, 4));
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn sharded_seeding_is_host_independent() {
+        let rng = ShardedRng::with_seed(7);
+
+        assert_eq!(rng.shards(), ShardedRng::DEFAULT_SHARDS);
+        assert_eq!(rng, ShardedRng::with_shards(7, ShardedRng::DEFAULT_SHARDS));
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn sharded_clone_forks() {
+        let rng = ShardedRng::with_shards(7, 4);
+        let cloned = rng.clone();
+
+        assert_eq!(cloned.shards(), 4);
+        assert_ne!(rng, cloned);
+        assert_ne!(rng.gen_u64(), cloned.gen_u64());
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic"))]
+    #[test]
+    fn sharded_no_duplicate_outputs_under_contention() {
+        crate::internal::testing::assert_no_duplicate_outputs_under_contention(
+            ShardedRng::with_shards(Default::default(), 4),
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "atomic", feature = "fmt"))]
+    #[test]
+    fn sharded_no_leaking_debug() {
+        let rng = ShardedRng::with_shards(Default::default(), 2);
+
+        assert_eq!(format!("{rng:?}"), "ShardedRng { shards: 2, .. }");
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn rand_compatibility() {
@@ -0,0 +1,329 @@
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+use crate::SeedBytes;
+
+const POOL_SIZE: usize = 4;
+const INIT_A: u32 = 0x43b0_d7e5;
+const MULT_A: u32 = 0x931e_8875;
+const INIT_B: u32 = 0x8b51_f9dd;
+const MULT_B: u32 = 0x58f3_8ded;
+const MIX_MULT_L: u32 = 0xca01_f9dd;
+const MIX_MULT_R: u32 = 0x4973_f715;
+const XSHIFT: u32 = u32::BITS / 2;
+
+#[inline]
+fn hashmix(value: u32, hash_const: &mut u32) -> u32 {
+    let mut value = value ^ *hash_const;
+
+    *hash_const = hash_const.wrapping_mul(MULT_A);
+    value = value.wrapping_mul(*hash_const);
+
+    value ^ (value >> XSHIFT)
+}
+
+#[inline]
+fn mix(x: u32, y: u32) -> u32 {
+    let result = MIX_MULT_L
+        .wrapping_mul(x)
+        .wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+
+    result ^ (result >> XSHIFT)
+}
+
+/// Splits an integer into 32-bit words, least significant first, dropping
+/// the leading zero words but always yielding at least one word.
+#[inline]
+fn int_words(value: u128) -> impl Iterator<Item = u32> {
+    let words = (u128::BITS - value.leading_zeros())
+        .div_ceil(u32::BITS)
+        .max(1);
+
+    (0..words).map(move |word| (value >> (word * u32::BITS)) as u32)
+}
+
+/// Expands input of any length into well-mixed seeds for any
+/// [`SeededCore`](crate::SeededCore) generator, so that a short seed, a
+/// string or a list of integers can seed a generator without hand-rolled
+/// packing or zero-padded keys.
+///
+/// The input is mixed into a pool with the algorithm of NumPy's
+/// `SeedSequence`, with integers split into 32-bit words, least significant
+/// first, as NumPy does. Byte and string input is split into little-endian
+/// words, followed by the byte length so that trailing zeroes are not lost.
+///
+/// # Example
+/// ```
+/// use turborand::{prelude::*, SeedSequence};
+///
+/// let rng = Rng::from_seed_sequence("experiment #1");
+///
+/// assert_eq!(
+///     Rng::from_seed_sequence(SeedSequence::from("experiment #1")).u64(..),
+///     rng.u64(..),
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SeedSequence {
+    pool: [u32; POOL_SIZE],
+}
+
+impl SeedSequence {
+    /// Creates a new [`SeedSequence`] from a list of 32-bit words.
+    #[inline]
+    #[must_use]
+    pub fn new(entropy: &[u32]) -> Self {
+        Self::from_words(entropy.iter().copied())
+    }
+
+    /// Creates a new [`SeedSequence`] from a generator's state words and a
+    /// label, for deriving labelled children of that generator.
+    #[cfg(any(
+        feature = "wyrand",
+        feature = "xoshiro",
+        feature = "pcg",
+        all(feature = "std", feature = "chacha")
+    ))]
+    #[inline]
+    pub(crate) fn labelled<I: IntoIterator<Item = u32>>(state: I, label: &[u8]) -> Self {
+        Self::from_words(state.into_iter().chain(byte_words(label)))
+    }
+
+    fn from_words<I: IntoIterator<Item = u32>>(entropy: I) -> Self {
+        let mut entropy = entropy.into_iter();
+        let mut hash_const = INIT_A;
+        let mut pool = [0u32; POOL_SIZE];
+
+        pool.iter_mut()
+            .for_each(|word| *word = hashmix(entropy.next().unwrap_or(0), &mut hash_const));
+
+        // Mix all the words together so later words affect earlier ones.
+        for source in 0..POOL_SIZE {
+            for target in 0..POOL_SIZE {
+                if source != target {
+                    pool[target] = mix(pool[target], hashmix(pool[source], &mut hash_const));
+                }
+            }
+        }
+
+        for value in entropy {
+            pool.iter_mut()
+                .for_each(|word| *word = mix(*word, hashmix(value, &mut hash_const)));
+        }
+
+        Self { pool }
+    }
+
+    /// Fills `words` with state derived from the pool, following the algorithm
+    /// of NumPy's `SeedSequence.generate_state`.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::SeedSequence;
+    ///
+    /// let mut state = [0u32; 2];
+    ///
+    /// SeedSequence::from(12345u64).generate_state(&mut state);
+    ///
+    /// assert_eq!(state, [0xa03d837c, 0xb5ae6482]);
+    /// ```
+    #[inline]
+    pub fn generate_state(&self, words: &mut [u32]) {
+        let mut state = self.states();
+
+        words.iter_mut().zip(&mut state).for_each(|(word, value)| {
+            *word = value;
+        });
+    }
+
+    /// Fills `buffer` with bytes derived from the pool, as little-endian
+    /// encoded words of [`SeedSequence::generate_state`].
+    #[inline]
+    pub fn fill_bytes(&self, buffer: &mut [u8]) {
+        buffer
+            .chunks_mut(core::mem::size_of::<u32>())
+            .zip(self.states())
+            .for_each(|(chunk, value)| {
+                chunk.copy_from_slice(&value.to_le_bytes()[..chunk.len()]);
+            });
+    }
+
+    /// Generates a seed of any [`SeedBytes`] type, such as the
+    /// [`SeededCore::Seed`](crate::SeededCore::Seed) of a generator, from the
+    /// bytes of [`SeedSequence::fill_bytes`].
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::{prelude::*, SeedSequence};
+    ///
+    /// let seed: u64 = SeedSequence::from("turborand").seed();
+    ///
+    /// assert_eq!(Rng::with_seed(seed), Rng::from_seed_sequence("turborand"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn seed<S: SeedBytes>(&self) -> S {
+        S::from_fill(|buffer| self.fill_bytes(buffer))
+    }
+
+    fn states(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut hash_const = INIT_B;
+
+        self.pool.iter().cycle().map(move |&word| {
+            let mut value = word ^ hash_const;
+
+            hash_const = hash_const.wrapping_mul(MULT_B);
+            value = value.wrapping_mul(hash_const);
+
+            value ^ (value >> XSHIFT)
+        })
+    }
+}
+
+impl From<u32> for SeedSequence {
+    #[inline]
+    fn from(value: u32) -> Self {
+        Self::from(u128::from(value))
+    }
+}
+
+impl From<u64> for SeedSequence {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self::from(u128::from(value))
+    }
+}
+
+impl From<u128> for SeedSequence {
+    #[inline]
+    fn from(value: u128) -> Self {
+        Self::from_words(int_words(value))
+    }
+}
+
+impl From<&[u32]> for SeedSequence {
+    #[inline]
+    fn from(entropy: &[u32]) -> Self {
+        Self::new(entropy)
+    }
+}
+
+impl From<&[u64]> for SeedSequence {
+    #[inline]
+    fn from(entropy: &[u64]) -> Self {
+        Self::from_words(
+            entropy
+                .iter()
+                .flat_map(|&value| int_words(u128::from(value))),
+        )
+    }
+}
+
+/// Splits bytes into little-endian 32-bit words, zero-padding the last word
+/// and appending the byte length.
+#[inline]
+pub(crate) fn byte_words(bytes: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    bytes
+        .chunks(core::mem::size_of::<u32>())
+        .map(|chunk| {
+            let mut word = [0u8; core::mem::size_of::<u32>()];
+
+            word[..chunk.len()].copy_from_slice(chunk);
+
+            u32::from_le_bytes(word)
+        })
+        .chain(int_words(bytes.len() as u128))
+}
+
+impl From<&[u8]> for SeedSequence {
+    #[inline]
+    fn from(entropy: &[u8]) -> Self {
+        Self::from_words(byte_words(entropy))
+    }
+}
+
+impl From<&str> for SeedSequence {
+    #[inline]
+    fn from(entropy: &str) -> Self {
+        Self::from(entropy.as_bytes())
+    }
+}
+
+impl From<&SeedSequence> for SeedSequence {
+    #[inline]
+    fn from(sequence: &SeedSequence) -> Self {
+        *sequence
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Debug for SeedSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SeedSequence").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_words() {
+        let mut words = [0u32; 3];
+
+        SeedSequence::from(12345u64).generate_state(&mut words);
+
+        assert_eq!(words, [0xa03d837c, 0xb5ae6482, 0xfa1f7a2f]);
+
+        SeedSequence::from([1u64, 1 << 40, 3, 4, 5, 6].as_slice()).generate_state(&mut words);
+
+        assert_eq!(words, [0xba1b612d, 0x265e2759, 0xe4d50a8f]);
+        assert_eq!(
+            SeedSequence::from(1u64 << 40),
+            SeedSequence::new(&[0, 1 << 8])
+        );
+        assert_eq!(SeedSequence::from(0u64), SeedSequence::new(&[0]));
+    }
+
+    #[test]
+    fn byte_input() {
+        let mut bytes = [0u8; 10];
+
+        SeedSequence::from("turborand").fill_bytes(&mut bytes);
+
+        assert_eq!(
+            bytes,
+            [0x60, 0x1f, 0x35, 0x22, 0xc2, 0xdb, 0xcd, 0xdd, 0xff, 0xe7]
+        );
+        assert_ne!(
+            SeedSequence::from([1u8, 0].as_slice()),
+            SeedSequence::from([1u8].as_slice())
+        );
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[test]
+    fn seeds_rng() {
+        use crate::{rng::Rng, SeededCore};
+
+        assert_eq!(
+            Rng::from_seed_sequence(12345u64),
+            Rng::with_seed(0xb5ae6482a03d837c)
+        );
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn seeds_chacha_rng() {
+        use crate::{chacha_rng::ChaChaRng, SeededCore};
+
+        let mut seed = [0u8; 40];
+
+        SeedSequence::from("turborand").fill_bytes(&mut seed);
+
+        assert_eq!(
+            ChaChaRng::from_seed_sequence("turborand"),
+            ChaChaRng::with_seed(seed)
+        );
+    }
+}
@@ -1,4 +1,8 @@
 #[cfg(feature = "chacha")]
 pub(crate) mod chacha;
+#[cfg(feature = "pcg")]
+pub(crate) mod pcg;
 #[cfg(feature = "wyrand")]
 pub(crate) mod wyrand;
+#[cfg(feature = "xoshiro")]
+pub(crate) mod xoshiro;
@@ -1,7 +1,16 @@
-use core::cell::UnsafeCell;
+use core::cell::{Cell, UnsafeCell};
 
 use self::utils::{calculate_block, init_state, AlignedSeed};
-use crate::internal::buffer::EntropyBuffer;
+use crate::{
+    internal::{
+        buffer::EntropyBuffer,
+        sha256::{Sha256, DIGEST_SIZE},
+    },
+    Error,
+};
+
+#[cfg(feature = "serialize")]
+use self::utils::FIRST_CONSTANT_WORD;
 
 #[cfg(feature = "fmt")]
 use crate::Debug;
@@ -9,23 +18,72 @@ use crate::Debug;
 #[cfg(feature = "serialize")]
 use crate::{Deserialize, Serialize, SerializeStruct, Visitor};
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 mod constants;
 pub(crate) mod utils;
 
-/// A ChaCha8 based Random Number Generator
-pub(crate) struct ChaCha8 {
+/// Mask for the keystream word position: a 64-bit block counter with 16
+/// words per block.
+const WORD_POS_MASK: u128 = (1 << 68) - 1;
+
+/// Range of the key words within the state.
+const KEY_WORDS: core::ops::Range<usize> = 4..12;
+
+/// Format version written in place of the first constant word of the state,
+/// marking that a `key_erasure` field follows the state and cache. Generators
+/// without key erasure keep the original two-field format, whose state always
+/// opens with the ChaCha constant.
+#[cfg(feature = "serialize")]
+const KEY_ERASURE_VERSION: u32 = 2;
+
+/// A ChaCha based Random Number Generator, running `DOUBLE_ROUNDS * 2` rounds
+/// per block.
+pub(crate) struct ChaCha<const DOUBLE_ROUNDS: usize> {
     state: UnsafeCell<[u32; 16]>,
     cache: EntropyBuffer<8>,
+    key_erasure: Cell<bool>,
+}
+
+/// A copy of the full state of a [`ChaCha`] source, including the buffered
+/// keystream and its cursor.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ChaChaState {
+    state: [u32; 16],
+    buffer: [u64; 8],
+    cursor: usize,
+    key_erasure: bool,
 }
 
-impl ChaCha8 {
+/// A ChaCha8 based Random Number Generator
+pub(crate) type ChaCha8 = ChaCha<4>;
+
+/// A ChaCha12 based Random Number Generator
+pub(crate) type ChaCha12 = ChaCha<6>;
+
+/// A ChaCha20 based Random Number Generator
+pub(crate) type ChaCha20 = ChaCha<10>;
+
+impl<const DOUBLE_ROUNDS: usize> ChaCha<DOUBLE_ROUNDS> {
+    /// Name of the variant, used for the redacted [`Debug`] output and
+    /// serialisation.
+    #[cfg(any(feature = "fmt", feature = "serialize"))]
+    const NAME: &'static str = match DOUBLE_ROUNDS {
+        4 => "ChaCha8",
+        6 => "ChaCha12",
+        10 => "ChaCha20",
+        _ => "ChaCha",
+    };
+
     #[cfg(feature = "serialize")]
     #[inline]
     #[must_use]
-    fn from_serde(state: [u32; 16], cache: EntropyBuffer<8>) -> Self {
+    fn from_serde(state: [u32; 16], cache: EntropyBuffer<8>, key_erasure: bool) -> Self {
         Self {
             state: UnsafeCell::new(state),
             cache,
+            key_erasure: Cell::new(key_erasure),
         }
     }
 
@@ -36,7 +94,7 @@ impl ChaCha8 {
         // therefore this is safe. This reference is used in only three cases,
         // in which all will never exist for long enough to overlap with a write.
         // This can also cause data races if called from different threads,
-        // but ChaCha8 is not Sync, so this won't happen.
+        // but ChaCha is not Sync, so this won't happen.
         unsafe { &*self.state.get() }
     }
 
@@ -45,7 +103,7 @@ impl ChaCha8 {
         // SAFETY: Pointer is kept here only for as long as the write happens,
         // with the array of data not needing to be dropped and instead it being
         // fine for being overwritten. This can also cause data races if called
-        // from different threads, but ChaCha8 is not Sync, so this won't happen.
+        // from different threads, but ChaCha is not Sync, so this won't happen.
         unsafe {
             self.state.get().write(state);
         }
@@ -71,7 +129,7 @@ impl ChaCha8 {
         // therefore this is safe. This mut reference is only used in this case,
         // in which all will never exist for long enough to overlap with a read.
         // This can also cause data races if called from different threads,
-        // but ChaCha8 is not Sync, so this won't happen.
+        // but ChaCha is not Sync, so this won't happen.
         let state = unsafe { &mut *self.state.get() };
 
         state[12] = (counter & 0xFFFF_FFFF) as u32;
@@ -84,7 +142,7 @@ impl ChaCha8 {
         // therefore this is safe. This mut reference is only used in this case,
         // in which all will never exist for long enough to overlap with a read.
         // This can also cause data races if called from different threads,
-        // but ChaCha8 is not Sync, so this won't happen.
+        // but ChaCha is not Sync, so this won't happen.
         let state = unsafe { &mut *self.state.get() };
 
         state[14] = (iv & 0xFFFF_FFFF) as u32;
@@ -107,6 +165,7 @@ impl ChaCha8 {
         Self {
             state: UnsafeCell::new(init_state(seed)),
             cache: EntropyBuffer::new(),
+            key_erasure: Cell::new(false),
         }
     }
 
@@ -118,13 +177,188 @@ impl ChaCha8 {
 
     #[inline]
     fn generate(&self) -> [u32; 16] {
-        let new_block = calculate_block::<4>(self.get_state());
+        if self.key_erasure.get() {
+            return self.generate_erasing();
+        }
+
+        let new_block = calculate_block::<DOUBLE_ROUNDS>(self.get_state());
 
         self.increment_word_block();
 
         new_block
     }
 
+    /// Generates two blocks, overwriting the key with the first half of the
+    /// first block and returning the rest as output, following the
+    /// [fast-key-erasure](https://blog.cr.yp.to/20170723-random.html) design.
+    /// Once the key is replaced, earlier output can no longer be recomputed.
+    fn generate_erasing(&self) -> [u32; 16] {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut first = calculate_block::<DOUBLE_ROUNDS>(self.get_state());
+
+        self.increment_word_block();
+
+        let second = calculate_block::<DOUBLE_ROUNDS>(self.get_state());
+
+        self.increment_word_block();
+
+        let mut state = *self.get_state();
+
+        state[KEY_WORDS].copy_from_slice(&first[..8]);
+
+        self.update_state(state);
+
+        let mut output = [0u32; 16];
+
+        output[..8].copy_from_slice(&first[8..]);
+        output[8..].copy_from_slice(&second[..8]);
+
+        // Wipe the stack copies of the key, so it is only left in the state.
+        #[cfg(feature = "zeroize")]
+        {
+            first.zeroize();
+            state.zeroize();
+        }
+
+        output
+    }
+
+    /// Returns whether fast key erasure is enabled.
+    #[inline]
+    pub(crate) fn get_key_erasure(&self) -> bool {
+        self.key_erasure.get()
+    }
+
+    /// Enables or disables fast key erasure. On enabling, the key is replaced
+    /// straight away and any buffered output is discarded, so that nothing
+    /// generated beforehand can be recomputed from the state.
+    #[inline]
+    pub(crate) fn set_key_erasure(&self, enabled: bool) {
+        self.key_erasure.set(enabled);
+
+        if enabled {
+            self.cache.empty_buffer();
+            self.generate_erasing();
+        }
+    }
+
+    /// Returns a copy of the full state, including the buffered keystream.
+    #[inline]
+    pub(crate) fn snapshot(&self) -> ChaChaState {
+        let (buffer, cursor) = self.cache.snapshot();
+
+        ChaChaState {
+            state: *self.get_state(),
+            buffer,
+            cursor,
+            key_erasure: self.key_erasure.get(),
+        }
+    }
+
+    /// Restores the full state from a [`ChaChaState`].
+    #[inline]
+    pub(crate) fn restore(&self, snapshot: &ChaChaState) {
+        self.update_state(snapshot.state);
+        self.cache.seek_entropy(snapshot.buffer, snapshot.cursor);
+        self.key_erasure.set(snapshot.key_erasure);
+    }
+
+    /// Derives the seed of a labelled child from the key, IV and current word
+    /// position, hashed with SHA-256 so that the child's seed reveals nothing
+    /// of the parent's key. Each output block hashes its index, the
+    /// length-prefixed label and then the fixed-size state, so that no two
+    /// labels yield the same input.
+    pub(crate) fn labelled_seed(&self, label: &[u8]) -> [u8; 40] {
+        let state = self.get_state();
+        let word_pos = self.get_word_pos();
+        let mut seed = [0u8; 40];
+
+        for (index, chunk) in seed.chunks_mut(DIGEST_SIZE).enumerate() {
+            let mut hasher = Sha256::new();
+
+            hasher.update(b"turborand labelled fork");
+            hasher.update(&[index as u8]);
+            hasher.update(&(label.len() as u64).to_le_bytes());
+            hasher.update(label);
+            state[KEY_WORDS]
+                .iter()
+                .chain(&state[14..])
+                .for_each(|word| hasher.update(&word.to_le_bytes()));
+            hasher.update(&word_pos.to_le_bytes());
+
+            let digest = hasher.finalize();
+
+            chunk.copy_from_slice(&digest[..chunk.len()]);
+        }
+
+        seed
+    }
+
+    /// Returns the stream selector, stored in the IV words of the state.
+    #[inline]
+    pub(crate) fn get_stream(&self) -> u64 {
+        self.get_iv()
+    }
+
+    /// Sets the stream selector, keeping the current word position in the
+    /// keystream. Fails without changing any state if fast key erasure is
+    /// enabled.
+    #[inline]
+    pub(crate) fn try_set_stream(&self, stream: u64) -> Result<(), Error> {
+        if self.key_erasure.get() {
+            return Err(Error::KeyErasureEnabled);
+        }
+
+        let word_pos = self.get_word_pos();
+
+        self.set_iv(stream);
+        self.seek(word_pos);
+
+        Ok(())
+    }
+
+    /// Returns the position of the next whole `u32` word in the keystream.
+    /// A partially consumed word counts as consumed. Only the lower 68 bits
+    /// are significant, as the block counter is 64 bits wide.
+    #[inline]
+    pub(crate) fn get_word_pos(&self) -> u128 {
+        let remaining_words = (self.cache.remaining_buffer() / 4) as u128;
+
+        (u128::from(self.get_counter()) << 4).wrapping_sub(remaining_words) & WORD_POS_MASK
+    }
+
+    /// Seeks to the given `u32` word position in the keystream. Fails without
+    /// changing any state if fast key erasure is enabled, as the key then
+    /// changes on every refill and there is no fixed keystream to seek in.
+    #[inline]
+    pub(crate) fn try_set_word_pos(&self, word_pos: u128) -> Result<(), Error> {
+        if self.key_erasure.get() {
+            return Err(Error::KeyErasureEnabled);
+        }
+
+        self.seek(word_pos);
+
+        Ok(())
+    }
+
+    /// Seeks to the given `u32` word position in the keystream, truncated to
+    /// 68 bits. If the position lands mid-block, that block is generated
+    /// and the buffer cursor is placed at the requested word.
+    #[inline]
+    fn seek(&self, word_pos: u128) {
+        let word_pos = word_pos & WORD_POS_MASK;
+        let word = (word_pos & 0xF) as usize;
+
+        self.set_counter((word_pos >> 4) as u64);
+
+        if word == 0 {
+            self.cache.empty_buffer();
+        } else {
+            self.cache
+                .seek_entropy(bytemuck::cast(self.generate()), word * 4);
+        }
+    }
+
     #[inline]
     pub(crate) fn rand<const OUTPUT: usize>(&self) -> [u8; OUTPUT] {
         let mut value = [0u8; OUTPUT];
@@ -137,84 +371,139 @@ impl ChaCha8 {
     #[inline]
     pub(crate) fn fill<B: AsMut<[u8]>>(&self, buffer: B) {
         self.cache
-            .fill_bytes_with_source(buffer, || bytemuck::cast(self.generate()))
+            .fill_bytes_with_source(buffer, || bytemuck::cast(self.generate()));
+
+        if self.key_erasure.get() {
+            self.cache.erase_consumed();
+        }
     }
 }
 
-impl Clone for ChaCha8 {
+#[cfg(feature = "zeroize")]
+impl<const DOUBLE_ROUNDS: usize> Drop for ChaCha<DOUBLE_ROUNDS> {
+    /// Wipes the key, counter and IV from memory. The cached keystream is
+    /// wiped separately by the [`EntropyBuffer`].
+    fn drop(&mut self) {
+        self.state.get_mut().zeroize();
+    }
+}
+
+impl<const DOUBLE_ROUNDS: usize> Clone for ChaCha<DOUBLE_ROUNDS> {
     fn clone(&self) -> Self {
         Self {
             state: UnsafeCell::new(*self.get_state()),
             cache: self.cache.clone(),
+            key_erasure: self.key_erasure.clone(),
         }
     }
 }
 
 #[cfg(feature = "fmt")]
-impl Debug for ChaCha8 {
+impl<const DOUBLE_ROUNDS: usize> Debug for ChaCha<DOUBLE_ROUNDS> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_tuple("ChaCha8").finish()
+        f.debug_tuple(Self::NAME).finish()
     }
 }
 
-impl PartialEq for ChaCha8 {
+impl<const DOUBLE_ROUNDS: usize> PartialEq for ChaCha<DOUBLE_ROUNDS> {
     fn eq(&self, other: &Self) -> bool {
-        self.get_state() == other.get_state() && self.cache == other.cache
+        self.get_state() == other.get_state()
+            && self.cache == other.cache
+            && self.key_erasure == other.key_erasure
     }
 }
 
-impl Eq for ChaCha8 {}
+impl<const DOUBLE_ROUNDS: usize> Eq for ChaCha<DOUBLE_ROUNDS> {}
 
 #[cfg(feature = "serialize")]
-impl Serialize for ChaCha8 {
+impl<const DOUBLE_ROUNDS: usize> Serialize for ChaCha<DOUBLE_ROUNDS> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut s = serializer.serialize_struct("ChaCha8", 2)?;
-        s.serialize_field("state", self.get_state())?;
+        if !self.key_erasure.get() {
+            let mut s = serializer.serialize_struct(Self::NAME, 2)?;
+            s.serialize_field("state", self.get_state())?;
+            s.serialize_field("cache", &self.cache)?;
+
+            return s.end();
+        }
+
+        // Formats without field names can only tell that a `key_erasure`
+        // field follows from the version in the state.
+        let mut state = *self.get_state();
+
+        state[0] = KEY_ERASURE_VERSION;
+
+        let mut s = serializer.serialize_struct(Self::NAME, 3)?;
+        let result = s.serialize_field("state", &state);
+
+        #[cfg(feature = "zeroize")]
+        state.zeroize();
+
+        result?;
         s.serialize_field("cache", &self.cache)?;
+        s.serialize_field("key_erasure", &true)?;
 
         s.end()
     }
 }
 
 #[cfg(feature = "serialize")]
-impl<'de> Deserialize<'de> for ChaCha8 {
+impl<'de, const DOUBLE_ROUNDS: usize> Deserialize<'de> for ChaCha<DOUBLE_ROUNDS> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &["state", "cache"];
+        const FIELDS: &[&str] = &["state", "cache", "key_erasure"];
 
         #[derive(Deserialize)]
-        #[serde(field_identifier, rename_all = "lowercase")]
+        #[serde(field_identifier, rename_all = "snake_case")]
         enum Field {
             State,
             Cache,
+            KeyErasure,
         }
 
-        struct ChaChaVisitor;
+        /// Restores the constant word replaced by the format version, returning
+        /// whether the versioned format with a `key_erasure` field was used.
+        fn take_version(state: &mut [u32; 16]) -> bool {
+            let versioned = state[0] == KEY_ERASURE_VERSION;
 
-        impl<'de> Visitor<'de> for ChaChaVisitor {
-            type Value = ChaCha8;
+            if versioned {
+                state[0] = FIRST_CONSTANT_WORD;
+            }
+
+            versioned
+        }
+
+        struct ChaChaVisitor<const ROUNDS: usize>;
+
+        impl<'de, const ROUNDS: usize> Visitor<'de> for ChaChaVisitor<ROUNDS> {
+            type Value = ChaCha<ROUNDS>;
 
             fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("struct ChaCha8")
+                write!(formatter, "struct {}", ChaCha::<ROUNDS>::NAME)
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
             where
                 A: serde::de::SeqAccess<'de>,
             {
-                let state = seq
+                let mut state = seq
                     .next_element()?
                     .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
                 let cache = seq
                     .next_element()?
                     .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
-
-                Ok(ChaCha8::from_serde(state, cache))
+                let key_erasure = if take_version(&mut state) {
+                    seq.next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?
+                } else {
+                    false
+                };
+
+                Ok(ChaCha::from_serde(state, cache, key_erasure))
             }
 
             fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -223,6 +512,7 @@ impl<'de> Deserialize<'de> for ChaCha8 {
             {
                 let mut state = None;
                 let mut cache = None;
+                let mut key_erasure = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -238,17 +528,28 @@ impl<'de> Deserialize<'de> for ChaCha8 {
                             }
                             cache = Some(map.next_value()?);
                         }
+                        Field::KeyErasure => {
+                            if key_erasure.is_some() {
+                                return Err(serde::de::Error::duplicate_field("key_erasure"));
+                            }
+                            key_erasure = Some(map.next_value()?);
+                        }
                     }
                 }
 
-                let state = state.ok_or_else(|| serde::de::Error::missing_field("state"))?;
+                let mut state = state.ok_or_else(|| serde::de::Error::missing_field("state"))?;
                 let cache = cache.ok_or_else(|| serde::de::Error::missing_field("cache"))?;
+                let key_erasure = if take_version(&mut state) {
+                    key_erasure.ok_or_else(|| serde::de::Error::missing_field("key_erasure"))?
+                } else {
+                    false
+                };
 
-                Ok(ChaCha8::from_serde(state, cache))
+                Ok(ChaCha::from_serde(state, cache, key_erasure))
             }
         }
 
-        deserializer.deserialize_struct("ChaCha8", FIELDS, ChaChaVisitor)
+        deserializer.deserialize_struct(Self::NAME, FIELDS, ChaChaVisitor::<DOUBLE_ROUNDS>)
     }
 }
 
@@ -519,4 +820,293 @@ mod tests {
             0x26, 0x02, 0x25, 0x7d, 0xd9, 0x6e, 0x47, 0x1e,
         ]
     );
+
+    #[cfg(feature = "fmt")]
+    #[test]
+    fn no_leaking_debug_round_variants() {
+        let source = ChaCha12::with_seed([0u8; 40].into());
+
+        assert_eq!(format!("{source:?}"), "ChaCha12");
+
+        let source = ChaCha20::with_seed([0u8; 40].into());
+
+        assert_eq!(format!("{source:?}"), "ChaCha20");
+    }
+
+    #[test]
+    fn round_variants_differ() {
+        let source8 = ChaCha8::with_seed([0u8; 40].into());
+        let source12 = ChaCha12::with_seed([0u8; 40].into());
+        let source20 = ChaCha20::with_seed([0u8; 40].into());
+
+        let output8 = source8.rand::<64>();
+        let output12 = source12.rand::<64>();
+        let output20 = source20.rand::<64>();
+
+        assert_ne!(&output8, &output12);
+        assert_ne!(&output12, &output20);
+        assert_ne!(&output8, &output20);
+    }
+
+    /// Test vectors from RFC 8439, Appendix A.1. The RFC uses a 32-bit block
+    /// counter with a 96-bit nonce, which lines up with the 64-bit counter and
+    /// 64-bit IV layout used here as long as the counter stays below 2^32.
+    macro_rules! rfc8439_vector {
+        ($test:ident, $seed:tt, $counter:expr, $output:tt) => {
+            #[test]
+            fn $test() {
+                let source = ChaCha20::with_seed($seed.into());
+
+                source.set_counter($counter);
+
+                let expected_output: [u8; 64] = $output;
+                let output = source.rand::<64>();
+
+                assert_eq!(&output, &expected_output);
+            }
+        };
+    }
+
+    rfc8439_vector!(
+        rfc8439_vector_one,
+        [0u8; 40],
+        0,
+        [
+            0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86,
+            0xbd, 0x28, 0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc,
+            0x8b, 0x77, 0x0d, 0xc7, 0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24,
+            0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37, 0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c,
+            0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+        ]
+    );
+
+    rfc8439_vector!(
+        rfc8439_vector_two,
+        [0u8; 40],
+        1,
+        [
+            0x9f, 0x07, 0xe7, 0xbe, 0x55, 0x51, 0x38, 0x7a, 0x98, 0xba, 0x97, 0x7c, 0x73, 0x2d,
+            0x08, 0x0d, 0xcb, 0x0f, 0x29, 0xa0, 0x48, 0xe3, 0x65, 0x69, 0x12, 0xc6, 0x53, 0x3e,
+            0x32, 0xee, 0x7a, 0xed, 0x29, 0xb7, 0x21, 0x76, 0x9c, 0xe6, 0x4e, 0x43, 0xd5, 0x71,
+            0x33, 0xb0, 0x74, 0xd8, 0x39, 0xd5, 0x31, 0xed, 0x1f, 0x28, 0x51, 0x0a, 0xfb, 0x45,
+            0xac, 0xe1, 0x0a, 0x1f, 0x4b, 0x79, 0x4d, 0x6f,
+        ]
+    );
+
+    rfc8439_vector!(
+        rfc8439_vector_three,
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        1,
+        [
+            0x3a, 0xeb, 0x52, 0x24, 0xec, 0xf8, 0x49, 0x92, 0x9b, 0x9d, 0x82, 0x8d, 0xb1, 0xce,
+            0xd4, 0xdd, 0x83, 0x20, 0x25, 0xe8, 0x01, 0x8b, 0x81, 0x60, 0xb8, 0x22, 0x84, 0xf3,
+            0xc9, 0x49, 0xaa, 0x5a, 0x8e, 0xca, 0x00, 0xbb, 0xb4, 0xa7, 0x3b, 0xda, 0xd1, 0x92,
+            0xb5, 0xc4, 0x2f, 0x73, 0xf2, 0xfd, 0x4e, 0x27, 0x36, 0x44, 0xc8, 0xb3, 0x61, 0x25,
+            0xa6, 0x4a, 0xdd, 0xeb, 0x00, 0x6c, 0x13, 0xa0,
+        ]
+    );
+
+    rfc8439_vector!(
+        rfc8439_vector_four,
+        [
+            0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        2,
+        [
+            0x72, 0xd5, 0x4d, 0xfb, 0xf1, 0x2e, 0xc4, 0x4b, 0x36, 0x26, 0x92, 0xdf, 0x94, 0x13,
+            0x7f, 0x32, 0x8f, 0xea, 0x8d, 0xa7, 0x39, 0x90, 0x26, 0x5e, 0xc1, 0xbb, 0xbe, 0xa1,
+            0xae, 0x9a, 0xf0, 0xca, 0x13, 0xb2, 0x5a, 0xa2, 0x6c, 0xb4, 0xa6, 0x48, 0xcb, 0x9b,
+            0x9d, 0x1b, 0xe6, 0x5b, 0x2c, 0x09, 0x24, 0xa6, 0x6c, 0x54, 0xd5, 0x45, 0xec, 0x1b,
+            0x73, 0x74, 0xf4, 0x87, 0x2e, 0x99, 0xf0, 0x96,
+        ]
+    );
+
+    rfc8439_vector!(
+        rfc8439_vector_five,
+        [
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        ],
+        0,
+        [
+            0xc2, 0xc6, 0x4d, 0x37, 0x8c, 0xd5, 0x36, 0x37, 0x4a, 0xe2, 0x04, 0xb9, 0xef, 0x93,
+            0x3f, 0xcd, 0x1a, 0x8b, 0x22, 0x88, 0xb3, 0xdf, 0xa4, 0x96, 0x72, 0xab, 0x76, 0x5b,
+            0x54, 0xee, 0x27, 0xc7, 0x8a, 0x97, 0x0e, 0x0e, 0x95, 0x5c, 0x14, 0xf3, 0xa8, 0x8e,
+            0x74, 0x1b, 0x97, 0xc2, 0x86, 0xf7, 0x5f, 0x8f, 0xc2, 0x99, 0xe8, 0x14, 0x83, 0x62,
+            0xfa, 0x19, 0x8a, 0x39, 0x53, 0x1b, 0xed, 0x6d,
+        ]
+    );
+
+    #[test]
+    fn word_pos_tracks_buffer_cursor() {
+        let source = ChaCha8::with_seed([0u8; 40].into());
+
+        assert_eq!(source.get_word_pos(), 0);
+
+        source.rand::<4>();
+
+        assert_eq!(source.get_word_pos(), 1);
+
+        source.rand::<61>();
+
+        assert_eq!(source.get_word_pos(), 17);
+
+        source.rand::<3>();
+
+        assert_eq!(source.get_word_pos(), 17);
+
+        source.rand::<128>();
+
+        assert_eq!(source.get_word_pos(), 49);
+    }
+
+    #[test]
+    fn set_word_pos_reproduces_output() {
+        let source = ChaCha8::with_seed([3u8; 40].into());
+
+        let output = source.rand::<256>();
+
+        for word_pos in [0usize, 1, 15, 16, 17, 37, 63] {
+            source.try_set_word_pos(word_pos as u128).unwrap();
+
+            assert_eq!(source.get_word_pos(), word_pos as u128);
+
+            let expected = &output[word_pos * 4..];
+            let mut actual = [0u8; 256];
+
+            source.fill(&mut actual[..expected.len()]);
+
+            assert_eq!(&actual[..expected.len()], expected);
+        }
+    }
+
+    #[test]
+    fn set_word_pos_wraps_at_68_bits() {
+        let source = ChaCha8::with_seed([3u8; 40].into());
+
+        source.try_set_word_pos((1 << 68) + 5).unwrap();
+
+        assert_eq!(source.get_word_pos(), 5);
+    }
+
+    #[test]
+    fn set_stream_keeps_word_pos() {
+        let source = ChaCha20::with_seed([0u8; 40].into());
+
+        assert_eq!(source.get_stream(), 0);
+
+        source.rand::<20>();
+        source.try_set_stream(1 << 32).unwrap();
+
+        assert_eq!(source.get_stream(), 1 << 32);
+        assert_eq!(source.get_word_pos(), 5);
+
+        let other = ChaCha20::with_seed([0u8; 40].into());
+
+        other.try_set_stream(1 << 32).unwrap();
+        other.try_set_word_pos(5).unwrap();
+
+        assert_eq!(source, other);
+    }
+
+    #[test]
+    fn key_erasure_wipes_consumed_output() {
+        let source = ChaCha8::with_seed([0u8; 40].into());
+        let initial_key: [u32; 8] = source.get_state()[KEY_WORDS].try_into().unwrap();
+
+        source.set_key_erasure(true);
+
+        assert_ne!(source.get_state()[KEY_WORDS], initial_key);
+
+        let key: [u32; 8] = source.get_state()[KEY_WORDS].try_into().unwrap();
+        let output = source.rand::<10>();
+        let snapshot = source.snapshot();
+        let bytes: &[u8] = bytemuck::cast_slice(&snapshot.buffer);
+
+        assert_ne!(source.get_state()[KEY_WORDS], key);
+        assert_eq!(&bytes[..10], &[0; 10]);
+        assert_ne!(&bytes[10..], &[0; 54]);
+        assert_ne!(output, [0; 10]);
+        assert!(snapshot.key_erasure);
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_tokens_with_key_erasure() {
+        use serde_test::{assert_tokens, Token};
+
+        let source = ChaCha8::from_serde(init_state([0u8; 40].into()), EntropyBuffer::new(), true);
+
+        assert_tokens(
+            &source,
+            &[
+                Token::Struct {
+                    name: "ChaCha8",
+                    len: 3,
+                },
+                Token::BorrowedStr("state"),
+                Token::Tuple { len: 16 },
+                Token::U32(KEY_ERASURE_VERSION),
+                Token::U32(857760878),
+                Token::U32(2036477234),
+                Token::U32(1797285236),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(0),
+                Token::TupleEnd,
+                Token::BorrowedStr("cache"),
+                Token::Tuple { len: 9 },
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(64),
+                Token::TupleEnd,
+                Token::BorrowedStr("key_erasure"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize_on_drop() {
+        use core::mem::MaybeUninit;
+
+        let mut source = MaybeUninit::new(ChaCha8::with_seed([7u8; 40].into()));
+
+        // SAFETY: The source is initialised, so it can be dropped in place. It
+        // is not used as a ChaCha8 instance afterwards.
+        unsafe { source.assume_init_drop() };
+
+        // SAFETY: Dropping only overwrites the integer fields in place, so the
+        // memory still holds a valid ChaCha8. It is only read from here, and
+        // padding bytes are never accessed.
+        let snapshot = unsafe { &*source.as_ptr() }.snapshot();
+
+        assert_eq!(snapshot.state, [0; 16]);
+        assert_eq!(snapshot.buffer, [0; 8]);
+        assert_eq!(snapshot.cursor, 0);
+    }
 }
@@ -19,6 +19,15 @@ impl std::ops::Deref for AlignedSeed {
     }
 }
 
+/// The first word of the ChaCha constant, which opens every state.
+#[cfg(feature = "serialize")]
+pub(super) const FIRST_CONSTANT_WORD: u32 = u32::from_le_bytes([
+    INITIAL_STATE[0],
+    INITIAL_STATE[1],
+    INITIAL_STATE[2],
+    INITIAL_STATE[3],
+]);
+
 #[inline]
 const fn pack_into_u32(input: &[u8]) -> u32 {
     assert!(input.len() == 4);
@@ -71,12 +80,13 @@ fn quarter_round<const A: usize, const B: usize, const C: usize, const D: usize>
 }
 
 #[inline]
+#[allow(clippy::manual_is_multiple_of)]
 pub(super) fn calculate_block<const DOUBLE_ROUNDS: usize>(state: &[u32; 16]) -> [u32; 16] {
     assert!(DOUBLE_ROUNDS % 2 == 0, "DOUBLE_ROUNDS must be even number");
 
     let mut new_block = *state;
 
-    // 8 Rounds of ChaCha, 4 loops * 2 rounds per loop = 8 Rounds
+    // 2 rounds per loop, so 4 loops = ChaCha8, 6 loops = ChaCha12, 10 loops = ChaCha20
     for _ in 0..DOUBLE_ROUNDS {
         // Odd Rounds
         quarter_round::<0, 4, 8, 12>(&mut new_block);
@@ -139,4 +149,24 @@ mod tests {
 
         assert_eq!(&state, &expected_state);
     }
+
+    #[test]
+    fn rfc8439_block_function_vector() {
+        // RFC 8439, Section 2.3.2
+        let state: [u32; 16] = [
+            0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, 0x03020100, 0x07060504, 0x0b0a0908,
+            0x0f0e0d0c, 0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c, 0x00000001, 0x09000000,
+            0x4a000000, 0x00000000,
+        ];
+
+        let state = calculate_block::<10>(&state);
+
+        let expected_state: [u32; 16] = [
+            0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033, 0x9aaa2204,
+            0x4e6cd4c3, 0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9, 0xd19c12b5, 0xb94e16de,
+            0xe883d0cb, 0x4e3c50a2,
+        ];
+
+        assert_eq!(&state, &expected_state);
+    }
 }
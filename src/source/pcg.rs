@@ -0,0 +1,315 @@
+use core::cell::Cell;
+
+#[cfg(feature = "fmt")]
+use crate::Debug;
+
+#[cfg(feature = "serialize")]
+use crate::{Deserialize, Serialize};
+
+/// LCG multiplier for the 64-bit state PCG variants.
+const MULTIPLIER_64: u64 = 0x5851_f42d_4c95_7f2d;
+
+/// The "cheap multiplier" used by the 128-bit state DXSM variant, as
+/// used by NumPy's `PCG64DXSM` and pcg-cpp's `cm_setseq_dxsm_128_64`.
+const CHEAP_MULTIPLIER_128: u64 = 0xda94_2042_e4dd_58b5;
+
+/// The default 128-bit LCG multiplier, which NumPy's `PCG64DXSM` still uses
+/// when seeding through `pcg_setseq_128_srandom_r`.
+const DEFAULT_MULTIPLIER_128: u128 = 0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645;
+
+#[inline]
+fn fill_with<B: AsMut<[u8]>, const SIZE: usize, F: Fn() -> [u8; SIZE]>(mut buffer: B, generate: F) {
+    let mut output = buffer.as_mut();
+
+    while output.len() >= SIZE {
+        let (target, remainder) = output.split_at_mut(SIZE);
+
+        target.copy_from_slice(&generate());
+
+        output = remainder;
+    }
+
+    if !output.is_empty() {
+        let input = generate();
+
+        let fill = output.len().min(input.len());
+
+        output.copy_from_slice(&input[..fill]);
+    }
+}
+
+/// A PCG32 (XSH-RR 64/32) Random Number Generator
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub(crate) struct Pcg32 {
+    state: Cell<u64>,
+    increment: Cell<u64>,
+}
+
+impl Pcg32 {
+    /// Creates a new [`Pcg32`] source from an initial state and a stream
+    /// selector, following the reference `pcg32_srandom_r` seeding.
+    #[inline]
+    pub(crate) fn with_stream(state: u64, stream: u64) -> Self {
+        let source = Self {
+            state: Cell::new(0),
+            increment: Cell::new(0),
+        };
+
+        source.reseed(state, stream);
+
+        source
+    }
+
+    /// Reseeds an existing [`Pcg32`] source with a new state and stream.
+    #[inline]
+    pub(crate) fn reseed(&self, state: u64, stream: u64) {
+        self.state.set(0);
+        self.increment.set((stream << 1) | 1);
+        self.step();
+        self.state.set(self.state.get().wrapping_add(state));
+        self.step();
+    }
+
+    /// Returns the current state and increment as little-endian 32-bit words.
+    #[inline]
+    pub(crate) fn state_words(&self) -> [u32; 4] {
+        let (state, increment) = (self.state.get(), self.increment.get());
+
+        [
+            state as u32,
+            (state >> 32) as u32,
+            increment as u32,
+            (increment >> 32) as u32,
+        ]
+    }
+
+    #[inline(always)]
+    fn step(&self) {
+        self.state.set(
+            self.state
+                .get()
+                .wrapping_mul(MULTIPLIER_64)
+                .wrapping_add(self.increment.get()),
+        );
+    }
+
+    #[inline(always)]
+    fn generate(&self) -> [u8; core::mem::size_of::<u32>()] {
+        let state = self.state.get();
+
+        self.step();
+
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rotation = (state >> 59) as u32;
+
+        xorshifted.rotate_right(rotation).to_le_bytes()
+    }
+
+    /// Generates random bytes from the RNG source.
+    #[inline]
+    pub(crate) fn rand<const SIZE: usize>(&self) -> [u8; SIZE] {
+        let mut output = [0u8; SIZE];
+
+        self.fill(&mut output);
+
+        output
+    }
+
+    #[inline]
+    pub(crate) fn fill<B: AsMut<[u8]>>(&self, buffer: B) {
+        fill_with(buffer, || self.generate());
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Debug for Pcg32 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Pcg32").finish()
+    }
+}
+
+/// A PCG64 DXSM (128-bit state, cheap multiplier) Random Number Generator
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub(crate) struct Pcg64Dxsm {
+    state: Cell<u128>,
+    increment: Cell<u128>,
+}
+
+impl Pcg64Dxsm {
+    /// Creates a new [`Pcg64Dxsm`] source from an initial state and a stream
+    /// selector, following NumPy's `pcg_setseq_128_srandom_r` seeding.
+    #[inline]
+    pub(crate) fn with_stream(state: u128, stream: u128) -> Self {
+        let source = Self {
+            state: Cell::new(0),
+            increment: Cell::new(0),
+        };
+
+        source.reseed(state, stream);
+
+        source
+    }
+
+    /// Reseeds an existing [`Pcg64Dxsm`] source with a new state and stream.
+    #[inline]
+    pub(crate) fn reseed(&self, state: u128, stream: u128) {
+        let increment = (stream << 1) | 1;
+        let seed_step = |state: u128| {
+            state
+                .wrapping_mul(DEFAULT_MULTIPLIER_128)
+                .wrapping_add(increment)
+        };
+
+        // Seeding steps with the default multiplier, only generation uses the
+        // cheap one.
+        self.state.set(seed_step(seed_step(0).wrapping_add(state)));
+        self.increment.set(increment);
+    }
+
+    /// Returns the current state and increment as little-endian 32-bit words.
+    #[inline]
+    pub(crate) fn state_words(&self) -> [u32; 8] {
+        let (state, increment) = (self.state.get(), self.increment.get());
+
+        core::array::from_fn(|index| {
+            let value = if index < 4 { state } else { increment };
+
+            (value >> ((index % 4) * 32)) as u32
+        })
+    }
+
+    #[inline(always)]
+    fn step(&self) {
+        self.state.set(
+            self.state
+                .get()
+                .wrapping_mul(u128::from(CHEAP_MULTIPLIER_128))
+                .wrapping_add(self.increment.get()),
+        );
+    }
+
+    #[inline(always)]
+    fn generate(&self) -> [u8; core::mem::size_of::<u64>()] {
+        let state = self.state.get();
+
+        self.step();
+
+        // DXSM output function, applied on the pre-iterated state.
+        let mut high = (state >> 64) as u64;
+        let low = (state as u64) | 1;
+
+        high ^= high >> 32;
+        high = high.wrapping_mul(CHEAP_MULTIPLIER_128);
+        high ^= high >> 48;
+        high = high.wrapping_mul(low);
+
+        high.to_le_bytes()
+    }
+
+    /// Generates random bytes from the RNG source.
+    #[inline]
+    pub(crate) fn rand<const SIZE: usize>(&self) -> [u8; SIZE] {
+        let mut output = [0u8; SIZE];
+
+        self.fill(&mut output);
+
+        output
+    }
+
+    #[inline]
+    pub(crate) fn fill<B: AsMut<[u8]>>(&self, buffer: B) {
+        fill_with(buffer, || self.generate());
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Debug for Pcg64Dxsm {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Pcg64Dxsm").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pcg32_reference_output() {
+        // Output from the reference `pcg32-demo`, seeded with 42 on stream 54.
+        let source = Pcg32::with_stream(42, 54);
+
+        let expected: [u32; 6] = [
+            0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+        ];
+
+        for value in expected {
+            assert_eq!(u32::from_le_bytes(source.generate()), value);
+        }
+    }
+
+    #[test]
+    fn pcg64_dxsm_reference_output() {
+        // Output of NumPy's `PCG64DXSM`, seeded through `pcg64_set_seed` with 42
+        // on stream 54.
+        let source = Pcg64Dxsm::with_stream(42, 54);
+
+        let expected: [u64; 6] = [
+            10070132190142401410,
+            3844026429387993677,
+            7444961654730855707,
+            16168510664873206229,
+            1313151724837583714,
+            17390713606665417014,
+        ];
+
+        for value in expected {
+            assert_eq!(u64::from_le_bytes(source.generate()), value);
+        }
+    }
+
+    #[test]
+    fn streams_diverge() {
+        let source1 = Pcg64Dxsm::with_stream(42, 1);
+        let source2 = Pcg64Dxsm::with_stream(42, 2);
+
+        assert_ne!(source1.rand::<16>(), source2.rand::<16>());
+
+        let source1 = Pcg32::with_stream(42, 1);
+        let source2 = Pcg32::with_stream(42, 2);
+
+        assert_ne!(source1.rand::<16>(), source2.rand::<16>());
+    }
+
+    #[test]
+    fn reseed() {
+        let source = Pcg32::with_stream(42, 54);
+
+        let value1 = source.rand::<4>();
+
+        source.reseed(42, 54);
+
+        let value2 = source.rand::<4>();
+
+        assert_eq!(
+            value1, value2,
+            "Output values should match after source is reseeded with the same state"
+        );
+    }
+
+    #[cfg(all(feature = "fmt", feature = "alloc"))]
+    #[test]
+    fn no_leaking_debug() {
+        #[cfg(all(feature = "alloc", not(feature = "std")))]
+        use alloc::format;
+
+        let source = Pcg32::with_stream(42, 54);
+
+        assert_eq!(format!("{source:?}"), "Pcg32");
+
+        let source = Pcg64Dxsm::with_stream(42, 54);
+
+        assert_eq!(format!("{source:?}"), "Pcg64Dxsm");
+    }
+}
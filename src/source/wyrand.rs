@@ -6,6 +6,21 @@ use crate::Debug;
 #[cfg(feature = "serialize")]
 use crate::{Deserialize, Serialize};
 
+/// Weyl sequence increment, added to the state on every generated output.
+pub(crate) const INCREMENT: u64 = 0xa076_1d64_78bd_642f;
+
+/// Multiplicative inverse of [`INCREMENT`] modulo 2^64, used to turn a
+/// difference in state back into a number of steps.
+const INCREMENT_INVERSE: u64 = 0x939c_72e4_af1e_62cf;
+
+/// The Wyrand output function, folding the 128-bit product of the state and
+/// a constant-xored copy of it into 64 bits.
+#[inline(always)]
+pub(crate) fn mix(state: u64) -> u64 {
+    let t = u128::from(state).wrapping_mul(u128::from(state ^ 0xe703_7ed1_a0b4_28db));
+    (t.wrapping_shr(64) ^ t) as u64
+}
+
 /// A Wyrand Random Number Generator
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
@@ -29,12 +44,48 @@ impl<S: State> WyRand<S> {
         self.state.set(seed);
     }
 
+    /// Returns the current state.
+    #[inline]
+    pub(crate) fn state(&self) -> u64 {
+        self.state.get()
+    }
+
+    /// Returns the current state as little-endian 32-bit words.
+    #[inline]
+    pub(crate) fn state_words(&self) -> [u32; 2] {
+        let state = self.state.get();
+
+        [state as u32, (state >> 32) as u32]
+    }
+
+    /// Advances the state by `steps` outputs, as if `steps` values had been generated.
+    #[inline]
+    pub(crate) fn advance(&self, steps: u64) {
+        self.state.update(steps.wrapping_mul(INCREMENT));
+    }
+
+    /// Rewinds the state by `steps` outputs.
+    #[inline]
+    pub(crate) fn rewind(&self, steps: u64) {
+        self.state
+            .update(steps.wrapping_mul(INCREMENT).wrapping_neg());
+    }
+
+    /// Returns the number of outputs `self` needs to advance by in order to
+    /// reach the same state as `other`, modulo 2^64.
+    #[inline]
+    pub(crate) fn distance<T: State>(&self, other: &WyRand<T>) -> u64 {
+        other
+            .state
+            .get()
+            .wrapping_sub(self.state.get())
+            .wrapping_mul(INCREMENT_INVERSE)
+    }
+
     #[inline(always)]
     fn generate(&self) -> [u8; core::mem::size_of::<u64>()] {
-        let state = self.state.update(0xa076_1d64_78bd_642f);
-        let t = u128::from(state).wrapping_mul(u128::from(state ^ 0xe703_7ed1_a0b4_28db));
-        let ret = (t.wrapping_shr(64) ^ t) as u64;
-        ret.to_le_bytes()
+        let state = self.state.update(INCREMENT);
+        mix(state).to_le_bytes()
     }
 
     /// Generates random bytes from the RNG source.
@@ -118,6 +169,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn increment_inverse() {
+        assert_eq!(INCREMENT.wrapping_mul(INCREMENT_INVERSE), 1);
+    }
+
+    #[test]
+    fn advance_and_rewind() {
+        let rng = WyRand::<CellState>::with_seed(1);
+        let stepped = WyRand::<CellState>::with_seed(1);
+
+        for _ in 0..10 {
+            stepped.generate();
+        }
+
+        rng.advance(10);
+
+        assert_eq!(rng.state.get(), stepped.state.get());
+        assert_eq!(rng.generate(), stepped.generate());
+
+        rng.rewind(11);
+
+        assert_eq!(rng.state.get(), 1);
+    }
+
+    #[test]
+    fn distance() {
+        let rng = WyRand::<CellState>::with_seed(1);
+        let other = WyRand::<CellState>::with_seed(1);
+
+        assert_eq!(rng.distance(&other), 0);
+
+        other.advance(3_000_000_000);
+
+        assert_eq!(rng.distance(&other), 3_000_000_000);
+        assert_eq!(other.distance(&rng), 3_000_000_000u64.wrapping_neg());
+
+        rng.advance(rng.distance(&other));
+
+        assert_eq!(rng, other);
+    }
+
     #[cfg(feature = "fmt")]
     #[test]
     fn clone() {
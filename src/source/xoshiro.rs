@@ -0,0 +1,517 @@
+use core::{cell::Cell, marker::PhantomData};
+
+#[cfg(feature = "fmt")]
+use crate::Debug;
+
+#[cfg(feature = "serialize")]
+use crate::{Deserialize, Serialize};
+
+/// Jump polynomial for Xoshiro256, equivalent to 2^128 calls to `generate`.
+const JUMP_256: [u64; 4] = [
+    0x180e_c6d3_3cfd_0aba,
+    0xd5a6_1266_f0c9_392c,
+    0xa958_2618_e03f_c9aa,
+    0x39ab_dc45_29b1_661c,
+];
+
+/// Long jump polynomial for Xoshiro256, equivalent to 2^192 calls to `generate`.
+const LONG_JUMP_256: [u64; 4] = [
+    0x76e1_5d3e_fefd_cbbf,
+    0xc500_4e44_1c52_2fb3,
+    0x7771_0069_854e_e241,
+    0x3910_9bb0_2acb_e635,
+];
+
+/// Jump polynomial for Xoroshiro128, equivalent to 2^64 calls to `generate`.
+const JUMP_128: [u64; 2] = [0x2bd7_a6a6_e99c_2ddc, 0x0992_ccaf_6a6f_ca05];
+
+/// Long jump polynomial for Xoroshiro128, equivalent to 2^96 calls to `generate`.
+const LONG_JUMP_128: [u64; 2] = [0x360f_d5f2_cf8d_5d99, 0x9c6e_6877_736c_46e3];
+
+/// SplitMix64 step, used to expand a seed into a valid non-zero state as
+/// recommended by the Xoshiro authors.
+#[inline]
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// An all-zero state is a fixed point for the Xoshiro family, so such seeds
+/// get replaced with a SplitMix64 expansion of zero instead.
+#[inline]
+fn non_zero_state<const N: usize>(seed: [u64; N]) -> [u64; N] {
+    if seed.iter().all(|&word| word == 0) {
+        let mut state = 0;
+        core::array::from_fn(|_| splitmix64(&mut state))
+    } else {
+        seed
+    }
+}
+
+/// Deserialises a state, rejecting the all-zero fixed point that the
+/// constructors never produce, as it would only ever output zeroes.
+#[cfg(feature = "serialize")]
+fn deserialize_state<'de, D, const N: usize>(deserializer: D) -> Result<Cell<[u64; N]>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    [u64; N]: Deserialize<'de>,
+{
+    let state = <[u64; N]>::deserialize(deserializer)?;
+
+    if state.iter().all(|&word| word == 0) {
+        return Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Other("an all-zero state"),
+            &"a non-zero state",
+        ));
+    }
+
+    Ok(Cell::new(state))
+}
+
+#[inline]
+fn fill_with<B: AsMut<[u8]>, F: Fn() -> u64>(mut buffer: B, generate: F) {
+    let mut output = buffer.as_mut();
+
+    while output.len() >= 8 {
+        let (target, remainder) = output.split_at_mut(8);
+
+        target.copy_from_slice(&generate().to_le_bytes());
+
+        output = remainder;
+    }
+
+    if !output.is_empty() {
+        let input = generate().to_le_bytes();
+
+        let fill = output.len().min(input.len());
+
+        output.copy_from_slice(&input[..fill]);
+    }
+}
+
+/// Output function applied on top of the Xoshiro256 linear engine.
+pub(crate) trait Scrambler {
+    /// Name of the scrambled variant, used for the redacted [`Debug`] output.
+    #[cfg(feature = "fmt")]
+    const NAME: &'static str;
+
+    /// Derives the output value from the current state.
+    fn scramble(state: &[u64; 4]) -> u64;
+}
+
+/// The `++` scrambler, recommended for general purpose use.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct PlusPlus;
+
+impl Scrambler for PlusPlus {
+    #[cfg(feature = "fmt")]
+    const NAME: &'static str = "Xoshiro256PlusPlus";
+
+    #[inline(always)]
+    fn scramble(state: &[u64; 4]) -> u64 {
+        state[0]
+            .wrapping_add(state[3])
+            .rotate_left(23)
+            .wrapping_add(state[0])
+    }
+}
+
+/// The `**` scrambler.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct StarStar;
+
+impl Scrambler for StarStar {
+    #[cfg(feature = "fmt")]
+    const NAME: &'static str = "Xoshiro256StarStar";
+
+    #[inline(always)]
+    fn scramble(state: &[u64; 4]) -> u64 {
+        state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+    }
+}
+
+/// A Xoshiro256 Random Number Generator, with the output scrambler
+/// determined by `S`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound = ""))]
+pub(crate) struct Xoshiro256<S: Scrambler> {
+    #[cfg_attr(feature = "serialize", serde(deserialize_with = "deserialize_state"))]
+    state: Cell<[u64; 4]>,
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    scrambler: PhantomData<S>,
+}
+
+impl<S: Scrambler> Xoshiro256<S> {
+    /// Creates a new [`Xoshiro256`] source with seeded state.
+    #[inline]
+    pub(crate) fn with_seed(seed: [u64; 4]) -> Self {
+        Self {
+            state: Cell::new(non_zero_state(seed)),
+            scrambler: PhantomData,
+        }
+    }
+
+    /// Reseeds an existing [`Xoshiro256`] source with a new seed state.
+    #[inline]
+    pub(crate) fn reseed(&self, seed: [u64; 4]) {
+        self.state.set(non_zero_state(seed));
+    }
+
+    /// Returns the current state as little-endian 32-bit words.
+    #[inline]
+    pub(crate) fn state_words(&self) -> [u32; 8] {
+        let [a, b, c, d] = self.state.get();
+
+        [
+            a as u32,
+            (a >> 32) as u32,
+            b as u32,
+            (b >> 32) as u32,
+            c as u32,
+            (c >> 32) as u32,
+            d as u32,
+            (d >> 32) as u32,
+        ]
+    }
+
+    #[inline(always)]
+    fn step(state: &mut [u64; 4]) {
+        let t = state[1] << 17;
+
+        state[2] ^= state[0];
+        state[3] ^= state[1];
+        state[1] ^= state[2];
+        state[0] ^= state[3];
+
+        state[2] ^= t;
+
+        state[3] = state[3].rotate_left(45);
+    }
+
+    #[inline(always)]
+    fn generate(&self) -> u64 {
+        let mut state = self.state.get();
+        let output = S::scramble(&state);
+
+        Self::step(&mut state);
+        self.state.set(state);
+
+        output
+    }
+
+    fn apply_jump(&self, polynomial: &[u64; 4]) {
+        let mut state = self.state.get();
+        let mut jumped = [0u64; 4];
+
+        for &word in polynomial {
+            for bit in 0..64 {
+                if (word >> bit) & 1 == 1 {
+                    jumped
+                        .iter_mut()
+                        .zip(state.iter())
+                        .for_each(|(acc, &value)| *acc ^= value);
+                }
+
+                Self::step(&mut state);
+            }
+        }
+
+        self.state.set(jumped);
+    }
+
+    /// Advances the state by 2^128 steps.
+    #[inline]
+    pub(crate) fn jump(&self) {
+        self.apply_jump(&JUMP_256);
+    }
+
+    /// Advances the state by 2^192 steps.
+    #[inline]
+    pub(crate) fn long_jump(&self) {
+        self.apply_jump(&LONG_JUMP_256);
+    }
+
+    /// Generates random bytes from the RNG source.
+    #[inline]
+    pub(crate) fn rand<const SIZE: usize>(&self) -> [u8; SIZE] {
+        let mut output = [0u8; SIZE];
+
+        self.fill(&mut output);
+
+        output
+    }
+
+    #[inline]
+    pub(crate) fn fill<B: AsMut<[u8]>>(&self, buffer: B) {
+        fill_with(buffer, || self.generate());
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<S: Scrambler> Debug for Xoshiro256<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple(S::NAME).finish()
+    }
+}
+
+/// A Xoroshiro128++ Random Number Generator
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[repr(transparent)]
+pub(crate) struct Xoroshiro128PlusPlus {
+    #[cfg_attr(feature = "serialize", serde(deserialize_with = "deserialize_state"))]
+    state: Cell<[u64; 2]>,
+}
+
+impl Xoroshiro128PlusPlus {
+    /// Creates a new [`Xoroshiro128PlusPlus`] source with seeded state.
+    #[inline]
+    pub(crate) fn with_seed(seed: [u64; 2]) -> Self {
+        Self {
+            state: Cell::new(non_zero_state(seed)),
+        }
+    }
+
+    /// Reseeds an existing [`Xoroshiro128PlusPlus`] source with a new seed state.
+    #[inline]
+    pub(crate) fn reseed(&self, seed: [u64; 2]) {
+        self.state.set(non_zero_state(seed));
+    }
+
+    /// Returns the current state as little-endian 32-bit words.
+    #[inline]
+    pub(crate) fn state_words(&self) -> [u32; 4] {
+        let [a, b] = self.state.get();
+
+        [a as u32, (a >> 32) as u32, b as u32, (b >> 32) as u32]
+    }
+
+    #[inline(always)]
+    fn step(state: &mut [u64; 2]) {
+        let s1 = state[1] ^ state[0];
+
+        state[0] = state[0].rotate_left(49) ^ s1 ^ (s1 << 21);
+        state[1] = s1.rotate_left(28);
+    }
+
+    #[inline(always)]
+    fn generate(&self) -> u64 {
+        let mut state = self.state.get();
+        let output = state[0]
+            .wrapping_add(state[1])
+            .rotate_left(17)
+            .wrapping_add(state[0]);
+
+        Self::step(&mut state);
+        self.state.set(state);
+
+        output
+    }
+
+    fn apply_jump(&self, polynomial: &[u64; 2]) {
+        let mut state = self.state.get();
+        let mut jumped = [0u64; 2];
+
+        for &word in polynomial {
+            for bit in 0..64 {
+                if (word >> bit) & 1 == 1 {
+                    jumped[0] ^= state[0];
+                    jumped[1] ^= state[1];
+                }
+
+                Self::step(&mut state);
+            }
+        }
+
+        self.state.set(jumped);
+    }
+
+    /// Advances the state by 2^64 steps.
+    #[inline]
+    pub(crate) fn jump(&self) {
+        self.apply_jump(&JUMP_128);
+    }
+
+    /// Advances the state by 2^96 steps.
+    #[inline]
+    pub(crate) fn long_jump(&self) {
+        self.apply_jump(&LONG_JUMP_128);
+    }
+
+    /// Generates random bytes from the RNG source.
+    #[inline]
+    pub(crate) fn rand<const SIZE: usize>(&self) -> [u8; SIZE] {
+        let mut output = [0u8; SIZE];
+
+        self.fill(&mut output);
+
+        output
+    }
+
+    #[inline]
+    pub(crate) fn fill<B: AsMut<[u8]>>(&self, buffer: B) {
+        fill_with(buffer, || self.generate());
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl Debug for Xoroshiro128PlusPlus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Xoroshiro128PlusPlus").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro256plusplus_reference_output() {
+        let source = Xoshiro256::<PlusPlus>::with_seed([1, 2, 3, 4]);
+
+        let expected: [u64; 10] = [
+            41943041,
+            58720359,
+            3588806011781223,
+            3591011842654386,
+            9228616714210784205,
+            9973669472204895162,
+            14011001112246962877,
+            12406186145184390807,
+            15849039046786891736,
+            10450023813501588000,
+        ];
+
+        for value in expected {
+            assert_eq!(source.generate(), value);
+        }
+    }
+
+    #[test]
+    fn xoshiro256starstar_reference_output() {
+        let source = Xoshiro256::<StarStar>::with_seed([1, 2, 3, 4]);
+
+        let expected: [u64; 10] = [
+            11520,
+            0,
+            1509978240,
+            1215971899390074240,
+            1216172134540287360,
+            607988272756665600,
+            16172922978634559625,
+            8476171486693032832,
+            10595114339597558777,
+            2904607092377533576,
+        ];
+
+        for value in expected {
+            assert_eq!(source.generate(), value);
+        }
+    }
+
+    #[test]
+    fn xoroshiro128plusplus_reference_output() {
+        let source = Xoroshiro128PlusPlus::with_seed([1, 2]);
+
+        let expected: [u64; 10] = [
+            393217,
+            669327710093319,
+            1732421326133921491,
+            11394790081659126983,
+            9555452776773192676,
+            3586421180005889563,
+            1691397964866707553,
+            10735626796753111697,
+            15216282715349408991,
+            14247243556711267923,
+        ];
+
+        for value in expected {
+            assert_eq!(source.generate(), value);
+        }
+    }
+
+    #[test]
+    fn xoshiro256_jumps() {
+        let source = Xoshiro256::<PlusPlus>::with_seed([1, 2, 3, 4]);
+
+        source.jump();
+
+        assert_eq!(
+            source.state.get(),
+            [
+                10122426448480695249,
+                8079205330032121950,
+                7289065458748526725,
+                9477464255293849680
+            ]
+        );
+
+        source.reseed([1, 2, 3, 4]);
+        source.long_jump();
+
+        assert_eq!(
+            source.state.get(),
+            [
+                678511610814637056,
+                15850499779492529430,
+                6002989639035333134,
+                3559352929785830385
+            ]
+        );
+    }
+
+    #[test]
+    fn xoroshiro128_jumps() {
+        let source = Xoroshiro128PlusPlus::with_seed([1, 2]);
+
+        source.jump();
+
+        assert_eq!(
+            source.state.get(),
+            [8625214420338730171, 17730401117375794498]
+        );
+
+        source.reseed([1, 2]);
+        source.long_jump();
+
+        assert_eq!(
+            source.state.get(),
+            [2219032219420146153, 9655216301158529667]
+        );
+    }
+
+    #[test]
+    fn zero_seed_is_replaced() {
+        let source = Xoshiro256::<PlusPlus>::with_seed([0; 4]);
+
+        assert_ne!(source.state.get(), [0; 4]);
+
+        let source = Xoroshiro128PlusPlus::with_seed([0; 2]);
+
+        assert_ne!(source.state.get(), [0; 2]);
+    }
+
+    #[cfg(all(feature = "fmt", feature = "alloc"))]
+    #[test]
+    fn no_leaking_debug() {
+        #[cfg(all(feature = "alloc", not(feature = "std")))]
+        use alloc::format;
+
+        let source = Xoshiro256::<PlusPlus>::with_seed([1, 2, 3, 4]);
+
+        assert_eq!(format!("{source:?}"), "Xoshiro256PlusPlus");
+
+        let source = Xoshiro256::<StarStar>::with_seed([1, 2, 3, 4]);
+
+        assert_eq!(format!("{source:?}"), "Xoshiro256StarStar");
+
+        let source = Xoroshiro128PlusPlus::with_seed([1, 2]);
+
+        assert_eq!(format!("{source:?}"), "Xoroshiro128PlusPlus");
+    }
+}
@@ -0,0 +1,316 @@
+//! Stateless random functions, for values that depend only on a seed and a
+//! key, such as grid coordinates or entity IDs.
+//!
+//! Rather than carrying a generator around and drawing from it in a fixed
+//! order, each value is computed directly from the seed and its key with the
+//! Wyrand mixing function, so the values for any set of keys can be computed
+//! in any order, in parallel, or recomputed later without storing them.
+//!
+//! [`at`] treats the seed as the starting state of an [`Rng`] and the key as a
+//! position in its output, so `at(seed, n)` is the `n`th `u64` that
+//! `Rng::with_seed(seed)` would generate. The typed helpers draw from an
+//! [`Rng`] positioned at the key, and so agree with [`at`] for the first value.
+//! This means nearby keys index nearby positions of the same sequence, which
+//! are nonetheless independent-looking outputs.
+//!
+//! # Example
+//! ```
+//! use turborand::stateless;
+//!
+//! const WORLD_SEED: u64 = 0x5eed;
+//!
+//! // Values for a cell are the same no matter when or where they are computed.
+//! let height = stateless::range_at(WORLD_SEED, (12, -4), 0..=255);
+//! let has_tree = stateless::chance_at(WORLD_SEED, (12, -4), 0.1);
+//!
+//! assert_eq!(stateless::range_at(WORLD_SEED, (12, -4), 0..=255), height);
+//! assert_eq!(stateless::chance_at(WORLD_SEED, (12, -4), 0.1), has_tree);
+//! ```
+use core::ops::RangeBounds;
+
+use crate::{
+    rng::Rng,
+    source::wyrand::{mix, INCREMENT},
+    Error, SeededCore, TurboRand,
+};
+
+/// Types that can be used as keys for the stateless functions. Integers are
+/// used as keys directly, while tuples and arrays of keys are folded into a
+/// single key through the Wyrand mixing function, so that `(1, 2)` and
+/// `(2, 1)` index different values.
+///
+/// # Example
+/// ```
+/// use turborand::stateless::{self, Key};
+///
+/// struct EntityId(u32);
+///
+/// impl Key for EntityId {
+///     fn to_key(&self) -> u64 {
+///         self.0.to_key()
+///     }
+/// }
+///
+/// assert_eq!(stateless::at(1, EntityId(7)), stateless::at(1, 7u32));
+/// ```
+pub trait Key {
+    /// Returns the 64-bit key to index values with.
+    fn to_key(&self) -> u64;
+}
+
+macro_rules! int_key {
+    ($($value:ty),+) => {
+        $(
+            impl Key for $value {
+                #[inline]
+                fn to_key(&self) -> u64 {
+                    *self as u64
+                }
+            }
+        )+
+    };
+}
+
+int_key!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Key for u128 {
+    #[inline]
+    fn to_key(&self) -> u64 {
+        combine(*self as u64, (*self >> 64) as u64)
+    }
+}
+
+impl Key for i128 {
+    #[inline]
+    fn to_key(&self) -> u64 {
+        (*self as u128).to_key()
+    }
+}
+
+impl<K: Key + ?Sized> Key for &K {
+    #[inline]
+    fn to_key(&self) -> u64 {
+        (**self).to_key()
+    }
+}
+
+impl<K: Key, const SIZE: usize> Key for [K; SIZE] {
+    #[inline]
+    fn to_key(&self) -> u64 {
+        self.as_slice().to_key()
+    }
+}
+
+impl<K: Key> Key for [K] {
+    #[inline]
+    fn to_key(&self) -> u64 {
+        let mut keys = self.iter().map(Key::to_key);
+
+        let first = keys.next().unwrap_or(0);
+
+        keys.fold(first, combine)
+    }
+}
+
+macro_rules! tuple_key {
+    ($first:ident, $($rest:ident),+) => {
+        impl<$first: Key, $($rest: Key),+> Key for ($first, $($rest),+) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn to_key(&self) -> u64 {
+                let ($first, $($rest),+) = self;
+
+                let key = $first.to_key();
+
+                $(let key = combine(key, $rest.to_key());)+
+
+                key
+            }
+        }
+    };
+}
+
+tuple_key!(A, B);
+tuple_key!(A, B, C);
+tuple_key!(A, B, C, D);
+
+/// Folds `next` into `key`, mixing `key` first so the order of keys matters.
+#[inline]
+fn combine(key: u64, next: u64) -> u64 {
+    mix(key.wrapping_add(INCREMENT)) ^ next
+}
+
+/// Returns an [`Rng`] positioned so that its next output is `at(seed, key)`.
+#[inline]
+fn positioned<K: Key>(seed: u64, key: K) -> Rng {
+    let rng = Rng::with_seed(seed);
+
+    rng.advance(key.to_key());
+
+    rng
+}
+
+/// Returns a random `u64` value for the given seed and key. This is the same
+/// value as the `key`th `u64` generated by an [`Rng`] seeded with `seed`.
+///
+/// # Example
+/// ```
+/// use turborand::{prelude::*, stateless};
+///
+/// let rng = Rng::with_seed(42);
+///
+/// rng.advance(3);
+///
+/// assert_eq!(stateless::at(42, 3u64), rng.gen_u64());
+/// ```
+#[inline]
+#[must_use]
+pub fn at<K: Key>(seed: u64, key: K) -> u64 {
+    // Same initial state as `Rng::with_seed`, advanced past `key` outputs.
+    let state = (seed << 1 | 1).wrapping_add(key.to_key().wrapping_add(1).wrapping_mul(INCREMENT));
+
+    mix(state)
+}
+
+/// Returns a random `f64` value between `0.0` and `1.0` for the given seed and key.
+///
+/// # Example
+/// ```
+/// use turborand::stateless;
+///
+/// let value = stateless::f64_at(42, (3, 4));
+///
+/// assert!((0.0..1.0).contains(&value));
+/// ```
+#[inline]
+#[must_use]
+pub fn f64_at<K: Key>(seed: u64, key: K) -> f64 {
+    positioned(seed, key).f64()
+}
+
+/// Returns a random `u64` value within the given bounds for the given seed and key.
+///
+/// # Panics
+///
+/// Panics if the range is empty or invalid.
+///
+/// # Example
+/// ```
+/// use turborand::stateless;
+///
+/// let value = stateless::range_at(42, [1, 2, 3], 10..20);
+///
+/// assert!((10..20).contains(&value));
+/// ```
+#[inline]
+#[must_use]
+pub fn range_at<K: Key>(seed: u64, key: K, bounds: impl RangeBounds<u64>) -> u64 {
+    positioned(seed, key).u64(bounds)
+}
+
+/// Returns a random `u64` value within the given bounds for the given seed and key.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidRange`] if the range is empty or invalid.
+///
+/// # Example
+/// ```
+/// use turborand::{stateless, Error};
+///
+/// assert!(stateless::try_range_at(42, 7u32, 10..20).is_ok());
+/// assert_eq!(stateless::try_range_at(42, 7u32, 20..10), Err(Error::InvalidRange));
+/// ```
+#[inline]
+pub fn try_range_at<K: Key>(
+    seed: u64,
+    key: K,
+    bounds: impl RangeBounds<u64>,
+) -> Result<u64, Error> {
+    positioned(seed, key).try_u64(bounds)
+}
+
+/// Returns a boolean value for the given seed and key, `true` with a
+/// probability of `rate`.
+///
+/// # Panics
+///
+/// Panics if `rate` is *not* a value between 0.0 and 1.0.
+///
+/// # Example
+/// ```
+/// use turborand::stateless;
+///
+/// assert!(stateless::chance_at(42, 7u32, 1.0));
+/// assert!(!stateless::chance_at(42, 7u32, 0.0));
+/// ```
+#[inline]
+#[must_use]
+pub fn chance_at<K: Key>(seed: u64, key: K, rate: f64) -> bool {
+    positioned(seed, key).chance(rate)
+}
+
+/// Returns a boolean value for the given seed and key, `true` with a
+/// probability of `rate`.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidRate`] if `rate` is *not* a value between 0.0 and 1.0.
+///
+/// # Example
+/// ```
+/// use turborand::{stateless, Error};
+///
+/// assert_eq!(stateless::try_chance_at(42, 7u32, 1.5), Err(Error::InvalidRate));
+/// ```
+#[inline]
+pub fn try_chance_at<K: Key>(seed: u64, key: K, rate: f64) -> Result<bool, Error> {
+    positioned(seed, key).try_chance(rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GenCore;
+
+    #[test]
+    fn matches_rng_sequence() {
+        let rng = Rng::with_seed(0xdead_beef);
+
+        for key in 0..64u64 {
+            assert_eq!(at(0xdead_beef, key), rng.gen_u64());
+        }
+
+        let rng = Rng::with_seed(5);
+
+        rng.advance(u64::MAX);
+
+        assert_eq!(at(5, u64::MAX), rng.gen_u64());
+        assert_eq!(f64_at(5, 9u8), positioned(5, 9u8).f64());
+    }
+
+    #[test]
+    fn composite_keys_depend_on_order() {
+        assert_ne!(at(1, (1, 2)), at(1, (2, 1)));
+        assert_ne!(at(1, (0, 0)), at(1, (0, 0, 0)));
+        assert_ne!(at(1, [1u64, 2]), at(1, [2u64, 1]));
+        assert_eq!(at(1, (3u8, 4u8)), at(1, [3u8, 4]));
+        assert_eq!(at(1, -1i8), at(1, u64::MAX));
+        assert_eq!(at(1, 7u128), at(1, (7u64, 0u64)));
+    }
+
+    #[test]
+    fn helpers_are_reproducible() {
+        let values: [u64; 16] =
+            core::array::from_fn(|index| range_at(99, (index, index * 3), 0..10));
+
+        assert!(values.iter().all(|value| (0..10).contains(value)));
+        assert!(values.iter().any(|&value| value != values[0]));
+        assert_eq!(
+            values,
+            core::array::from_fn(|index| range_at(99, (index, index * 3), 0..10))
+        );
+        assert_eq!(try_range_at(99, 0u32, 5..5), Err(Error::InvalidRange));
+        assert_eq!(try_chance_at(99, 0u32, -0.5), Err(Error::InvalidRate));
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(feature = "fmt")]
+use core::fmt::Debug;
+
+use crate::{Error, StreamableCore};
+
+/// Hands out non-overlapping [`StreamableCore`] generators from a single root
+/// seed, with the `i`th stream built directly from `i` in constant time. This
+/// lets parallel workers, such as `rayon` tasks or MPI ranks, each construct
+/// their own generator from their index, without coordinating on the order
+/// in which generators are [forked](crate::ForkableCore::fork).
+///
+/// Streams are only non-overlapping while each one stays within its share of
+/// the generator's period. For `Rng` and `AtomicRng`, the 2^64 period is split
+/// into 2^24 streams of 2^40 outputs each, and drawing more than 2^40 outputs
+/// from one stream silently runs on into the next. Use `ChaChaRng`, whose
+/// streams each have their own counter, if a stream needs more output than that.
+///
+/// # Example
+/// ```
+/// use turborand::{prelude::*, RngStreams};
+///
+/// let streams = RngStreams::<Rng>::new(42);
+///
+/// // Each worker builds its own stream, in whatever order they start.
+/// let worker17 = streams.stream(17);
+/// let worker3 = streams.stream(3);
+///
+/// assert_eq!(worker17.u64(..), streams.stream(17).u64(..));
+/// assert_ne!(worker3.u64(..), worker17.u64(..));
+/// ```
+pub struct RngStreams<R: StreamableCore> {
+    seed: R::Seed,
+}
+
+impl<R: StreamableCore> RngStreams<R>
+where
+    R::Seed: Clone,
+{
+    /// Creates a new [`RngStreams`] from the root seed all streams derive from.
+    #[inline]
+    #[must_use]
+    pub fn new(seed: R::Seed) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the generator for stream `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` exceeds the number of non-overlapping streams the
+    /// generator can be split into.
+    #[inline]
+    #[must_use]
+    pub fn stream(&self, index: u64) -> R {
+        match self.try_stream(index) {
+            Ok(stream) => stream,
+            Err(_) => panic!("stream index {index} is out of range for non-overlapping streams"),
+        }
+    }
+
+    /// Returns the generator for stream `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRange`] if `index` exceeds the number of
+    /// non-overlapping streams the generator can be split into.
+    #[inline]
+    pub fn try_stream(&self, index: u64) -> Result<R, Error> {
+        R::try_with_stream(self.seed.clone(), index)
+    }
+}
+
+impl<R: StreamableCore> Clone for RngStreams<R>
+where
+    R::Seed: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            seed: self.seed.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<R: StreamableCore> Debug for RngStreams<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("RngStreams").finish()
+    }
+}
@@ -0,0 +1,146 @@
+//! Free functions drawing directly from the thread-local [`Rng`], for call
+//! sites that want a quick random value without constructing a generator.
+//!
+//! Each function is a shorthand for the [`TurboRand`] method of the same name
+//! on [`thread_rng`], and panics in the same cases. The fallible `try_`
+//! variants are available through [`thread_rng`] itself.
+//!
+//! [`Rng`]: crate::rng::Rng
+use core::ops::RangeBounds;
+
+use crate::{rng::thread_rng, Fillable, TurboRand};
+
+macro_rules! range_fn {
+    ($($value:ident),+) => {
+        $(
+            #[doc = concat!("Returns a random `", stringify!($value), "` value within the given bounds.")]
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty or invalid.
+            #[inline]
+            #[must_use]
+            pub fn $value(bounds: impl RangeBounds<$value>) -> $value {
+                thread_rng().$value(bounds)
+            }
+        )+
+    };
+}
+
+range_fn!(u128, i128, u64, i64, u32, i32, u16, i16, u8, i8, usize, isize);
+
+macro_rules! value_fn {
+    ($($func:ident -> $value:ty, $doc:tt;)+) => {
+        $(
+            #[doc = $doc]
+            #[inline]
+            #[must_use]
+            pub fn $func() -> $value {
+                thread_rng().$func()
+            }
+        )+
+    };
+}
+
+value_fn! {
+    f32 -> f32, "Returns a random `f32` value between `0.0` and `1.0`.";
+    f32_normalized -> f32, "Returns a random `f32` value between `-1.0` and `1.0`.";
+    f64 -> f64, "Returns a random `f64` value between `0.0` and `1.0`.";
+    f64_normalized -> f64, "Returns a random `f64` value between `-1.0` and `1.0`.";
+    bool -> bool, "Returns a random boolean value.";
+    alphabetic -> char, "Generates a random `char` in ranges a-z and A-Z.";
+    alphanumeric -> char, "Generates a random `char` in ranges a-z, A-Z and 0-9.";
+    lowercase -> char, "Generates a random `char` in the range a-z.";
+    uppercase -> char, "Generates a random `char` in the range A-Z.";
+}
+
+/// Returns a boolean value, `true` with a probability of `rate`.
+///
+/// # Panics
+///
+/// Panics if `rate` is *not* a value between 0.0 and 1.0.
+#[inline]
+#[must_use]
+pub fn chance(rate: f64) -> bool {
+    thread_rng().chance(rate)
+}
+
+/// Returns a random `char` within the given bounds.
+///
+/// # Panics
+///
+/// Panics if the range is empty or invalid.
+#[inline]
+#[must_use]
+pub fn char(bounds: impl RangeBounds<char>) -> char {
+    thread_rng().char(bounds)
+}
+
+/// Generates a random digit in the given `radix`.
+///
+/// # Panics
+///
+/// Panics if the `radix` is zero or greater than 36.
+#[inline]
+#[must_use]
+pub fn digit(radix: u8) -> char {
+    thread_rng().digit(radix)
+}
+
+/// Returns a random index within the given bounds.
+///
+/// # Panics
+///
+/// Panics if the range is empty or invalid.
+#[inline]
+#[must_use]
+pub fn index(bounds: impl RangeBounds<usize>) -> usize {
+    thread_rng().index(bounds)
+}
+
+/// Samples a random item from a slice, or returns `None` if it is empty.
+#[inline]
+#[must_use]
+pub fn sample<T>(list: &[T]) -> Option<&T> {
+    thread_rng().sample(list)
+}
+
+/// Shuffles a slice in place.
+#[inline]
+pub fn shuffle<T>(slice: &mut [T]) {
+    thread_rng().shuffle(slice);
+}
+
+/// Fills a mutable slice with random values.
+#[inline]
+pub fn fill<T: Fillable + ?Sized>(dest: &mut T) {
+    thread_rng().fill(dest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rng::Rng, SeededCore};
+
+    #[test]
+    fn free_functions_draw_from_thread_local() {
+        let expected = Rng::with_seed(Default::default());
+
+        expected.reseed(11);
+        Rng::reseed_local(11);
+
+        assert_eq!(u64(..), expected.u64(..));
+        assert_eq!(i8(-5..5), expected.i8(-5..5));
+        assert_eq!(f64(), expected.f64());
+        assert_eq!(alphanumeric(), expected.alphanumeric());
+        assert_eq!(sample(&[1, 2, 3]), expected.sample(&[1, 2, 3]));
+
+        let mut values = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut expected_values = values;
+
+        shuffle(&mut values);
+        expected.shuffle(&mut expected_values);
+
+        assert_eq!(values, expected_values);
+    }
+}
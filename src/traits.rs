@@ -8,6 +8,7 @@ use crate::{
     methods::{
         gen_int_const, trait_fillable_gen, trait_float_gen, trait_rand_chars, trait_range_int,
     },
+    Error, SeedSequence,
 };
 
 /// Enum for determining the kind of PRNG, whether a fast one,
@@ -123,6 +124,143 @@ pub trait SeededCore: TurboCore {
 
     /// Reseeds the [`SeededCore`] with a new seed/state.
     fn reseed(&self, seed: Self::Seed);
+
+    /// Creates a new [`SeededCore`] with a seed drawn from the given [`EntropySource`].
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// struct Counter;
+    ///
+    /// impl EntropySource for Counter {
+    ///     fn fill_entropy(&self, buffer: &mut [u8]) {
+    ///         buffer.iter_mut().enumerate().for_each(|(i, byte)| *byte = i as u8);
+    ///     }
+    /// }
+    ///
+    /// let rng1 = Rng::from_entropy_source(&Counter);
+    /// let rng2 = Rng::from_entropy_source(&Counter);
+    ///
+    /// assert_eq!(rng1.u64(..), rng2.u64(..));
+    /// ```
+    #[inline]
+    fn from_entropy_source<E: EntropySource + ?Sized>(source: &E) -> Self
+    where
+        Self: Sized,
+        Self::Seed: SeedBytes,
+    {
+        Self::with_seed(Self::Seed::from_fill(|buffer| source.fill_entropy(buffer)))
+    }
+
+    /// Creates a new [`SeededCore`] with a seed expanded from the given
+    /// [`SeedSequence`], or from any input that can be converted into one,
+    /// such as integers, byte slices or strings.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng1 = Rng::from_seed_sequence("level-1");
+    /// let rng2 = Rng::from_seed_sequence("level-1");
+    ///
+    /// assert_eq!(rng1.u64(..), rng2.u64(..));
+    /// ```
+    #[inline]
+    fn from_seed_sequence<S: Into<SeedSequence>>(sequence: S) -> Self
+    where
+        Self: Sized,
+        Self::Seed: SeedBytes,
+    {
+        Self::with_seed(sequence.into().seed())
+    }
+
+    /// Creates a new [`SeededCore`] with a seed drawn from the given [`EntropySource`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the source fails to provide entropy.
+    #[inline]
+    fn try_from_entropy_source<E: EntropySource + ?Sized>(source: &E) -> Result<Self, Error>
+    where
+        Self: Sized,
+        Self::Seed: SeedBytes,
+    {
+        let mut result = Ok(());
+
+        let seed = Self::Seed::from_fill(|buffer| {
+            if result.is_ok() {
+                result = source.try_fill_entropy(buffer);
+            }
+        });
+
+        result.map(|_| Self::with_seed(seed))
+    }
+}
+
+/// A source of entropy, for seeding [`SeededCore`] PRNGs. With the `std`
+/// feature, the OS provided source is used by default, but any implementor
+/// can be used to seed generators directly via
+/// [`SeededCore::from_entropy_source`], such as hardware sources on `no_std`
+/// targets or fixed sources for deterministic test harnesses.
+pub trait EntropySource {
+    /// Fills the buffer entirely with entropy.
+    fn fill_entropy(&self, buffer: &mut [u8]);
+
+    /// Fills the buffer entirely with entropy, reporting whether the source
+    /// failed to do so. Sources that can fail should override this method,
+    /// as the default implementation always succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the source could not provide entropy.
+    #[inline]
+    fn try_fill_entropy(&self, buffer: &mut [u8]) -> Result<(), Error> {
+        self.fill_entropy(buffer);
+
+        Ok(())
+    }
+}
+
+/// Seed types that can be built from raw bytes, such as those yielded by an
+/// [`EntropySource`]. Integer words are assembled from little-endian bytes.
+pub trait SeedBytes: Sized {
+    /// Builds the seed by having `fill` write the raw bytes for it. `fill` is
+    /// called exactly once, with a buffer the size of the whole seed.
+    fn from_fill<F: FnMut(&mut [u8])>(fill: F) -> Self;
+}
+
+impl SeedBytes for u64 {
+    #[inline]
+    fn from_fill<F: FnMut(&mut [u8])>(mut fill: F) -> Self {
+        let mut bytes = [0u8; core::mem::size_of::<u64>()];
+
+        fill(&mut bytes);
+
+        u64::from_le_bytes(bytes)
+    }
+}
+
+impl<const N: usize> SeedBytes for [u8; N] {
+    #[inline]
+    fn from_fill<F: FnMut(&mut [u8])>(mut fill: F) -> Self {
+        let mut bytes = [0u8; N];
+
+        fill(&mut bytes);
+
+        bytes
+    }
+}
+
+impl<const N: usize> SeedBytes for [u64; N] {
+    #[inline]
+    fn from_fill<F: FnMut(&mut [u8])>(mut fill: F) -> Self {
+        let mut bytes = [[0u8; core::mem::size_of::<u64>()]; N];
+
+        fill(bytes.as_flattened_mut());
+
+        bytes.map(u64::from_le_bytes)
+    }
 }
 
 /// A marker trait to be applied to anything that implements [`TurboCore`]
@@ -132,6 +270,11 @@ pub trait SeededCore: TurboCore {
 /// This trait is provided as guidance only, and it is for the implementor to
 /// ensure that their PRNG source qualifies as cryptographically secure. Must
 /// be manually applied and is not an auto-trait.
+///
+/// It makes no promise of backtracking resistance: should the state of a
+/// generator be exposed, earlier output may be recomputed from it, unless the
+/// generator erases its key as it goes, such as the `chacha_rng` generators
+/// with key erasure enabled.
 pub trait SecureCore: TurboCore {}
 
 /// Extension trait for automatically implementing all [`TurboRand`] methods,
@@ -147,6 +290,8 @@ pub trait TurboRand: TurboCore + GenCore {
     /// Panics if the range is empty or invalid.
     #[inline]
     fn u128(&self, bounds: impl RangeBounds<u128>) -> u128 {
+        // Excluded bounds saturate rather than being rejected, so that ranges
+        // such as `..0` keep returning the bound itself.
         let lower = match bounds.start_bound() {
             Bound::Included(lower) => *lower,
             Bound::Excluded(lower) => lower.saturating_add(1),
@@ -158,9 +303,35 @@ pub trait TurboRand: TurboCore + GenCore {
             Bound::Unbounded => u128::MAX,
         };
 
-        assert!(lower <= upper, "Range should not be zero sized or invalid");
+        match self.try_u128(lower..=upper) {
+            Ok(value) => value,
+            Err(_) => panic!("Range should not be zero sized or invalid"),
+        }
+    }
 
-        match (lower, upper) {
+    /// Returns a random `u128` within a given range bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRange`] if the range is empty or invalid.
+    #[inline]
+    fn try_u128(&self, bounds: impl RangeBounds<u128>) -> Result<u128, Error> {
+        let lower = match bounds.start_bound() {
+            Bound::Included(lower) => *lower,
+            Bound::Excluded(lower) => lower.checked_add(1).ok_or(Error::InvalidRange)?,
+            Bound::Unbounded => u128::MIN,
+        };
+        let upper = match bounds.end_bound() {
+            Bound::Included(upper) => *upper,
+            Bound::Excluded(upper) => upper.checked_sub(1).ok_or(Error::InvalidRange)?,
+            Bound::Unbounded => u128::MAX,
+        };
+
+        if lower > upper {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(match (lower, upper) {
             (u128::MIN, u128::MAX) => self.gen_u128(),
             (_, _) => {
                 let range = upper.wrapping_sub(lower).wrapping_add(1);
@@ -177,7 +348,7 @@ pub trait TurboRand: TurboCore + GenCore {
                 }
                 lower.wrapping_add(high)
             }
-        }
+        })
     }
 
     /// Returns a random `i128` within a given range bound.
@@ -187,6 +358,8 @@ pub trait TurboRand: TurboCore + GenCore {
     /// Panics if the range is empty or invalid.
     #[inline]
     fn i128(&self, bounds: impl RangeBounds<i128>) -> i128 {
+        // Excluded bounds saturate rather than being rejected, so that ranges
+        // such as `..0` keep returning the bound itself.
         let lower = match bounds.start_bound() {
             Bound::Included(lower) => *lower,
             Bound::Excluded(lower) => lower.saturating_add(1),
@@ -198,9 +371,35 @@ pub trait TurboRand: TurboCore + GenCore {
             Bound::Unbounded => i128::MAX,
         };
 
-        assert!(lower <= upper, "Range should not be zero sized or invalid");
+        match self.try_i128(lower..=upper) {
+            Ok(value) => value,
+            Err(_) => panic!("Range should not be zero sized or invalid"),
+        }
+    }
 
-        match (lower, upper) {
+    /// Returns a random `i128` within a given range bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRange`] if the range is empty or invalid.
+    #[inline]
+    fn try_i128(&self, bounds: impl RangeBounds<i128>) -> Result<i128, Error> {
+        let lower = match bounds.start_bound() {
+            Bound::Included(lower) => *lower,
+            Bound::Excluded(lower) => lower.checked_add(1).ok_or(Error::InvalidRange)?,
+            Bound::Unbounded => i128::MIN,
+        };
+        let upper = match bounds.end_bound() {
+            Bound::Included(upper) => *upper,
+            Bound::Excluded(upper) => upper.checked_sub(1).ok_or(Error::InvalidRange)?,
+            Bound::Unbounded => i128::MAX,
+        };
+
+        if lower > upper {
+            return Err(Error::InvalidRange);
+        }
+
+        Ok(match (lower, upper) {
             (i128::MIN, i128::MAX) => self.gen_i128(),
             (_, _) => {
                 let range = upper.wrapping_sub(lower).wrapping_add(1) as u128;
@@ -217,20 +416,63 @@ pub trait TurboRand: TurboCore + GenCore {
                 }
                 lower.wrapping_add(high as i128)
             }
-        }
+        })
     }
 
-    trait_range_int!(u64, u64, u128, gen_u64, "Returns a random `u64` value.");
-    trait_range_int!(i64, u64, u128, gen_i64, "Returns a random `i64` value.");
-    trait_range_int!(u32, u32, u64, gen_u32, "Returns a random `u32` value.");
-    trait_range_int!(i32, u32, u64, gen_i32, "Returns a random `i32` value.");
-    trait_range_int!(u16, u16, u32, gen_u16, "Returns a random `u16` value.");
-    trait_range_int!(i16, u16, u32, gen_i16, "Returns a random `i16` value.");
-    trait_range_int!(u8, u8, u16, gen_u8, "Returns a random `u8` value.");
-    trait_range_int!(i8, u8, u16, gen_i8, "Returns a random `i8` value.");
+    trait_range_int!(
+        u64,
+        try_u64,
+        u64,
+        u128,
+        gen_u64,
+        "Returns a random `u64` value."
+    );
+    trait_range_int!(
+        i64,
+        try_i64,
+        u64,
+        u128,
+        gen_i64,
+        "Returns a random `i64` value."
+    );
+    trait_range_int!(
+        u32,
+        try_u32,
+        u32,
+        u64,
+        gen_u32,
+        "Returns a random `u32` value."
+    );
+    trait_range_int!(
+        i32,
+        try_i32,
+        u32,
+        u64,
+        gen_i32,
+        "Returns a random `i32` value."
+    );
+    trait_range_int!(
+        u16,
+        try_u16,
+        u16,
+        u32,
+        gen_u16,
+        "Returns a random `u16` value."
+    );
+    trait_range_int!(
+        i16,
+        try_i16,
+        u16,
+        u32,
+        gen_i16,
+        "Returns a random `i16` value."
+    );
+    trait_range_int!(u8, try_u8, u8, u16, gen_u8, "Returns a random `u8` value.");
+    trait_range_int!(i8, try_i8, u8, u16, gen_i8, "Returns a random `i8` value.");
     #[cfg(target_pointer_width = "16")]
     trait_range_int!(
         usize,
+        try_usize,
         u16,
         u32,
         gen_usize,
@@ -239,6 +481,7 @@ pub trait TurboRand: TurboCore + GenCore {
     #[cfg(target_pointer_width = "32")]
     trait_range_int!(
         usize,
+        try_usize,
         u32,
         u64,
         gen_usize,
@@ -247,6 +490,7 @@ pub trait TurboRand: TurboCore + GenCore {
     #[cfg(target_pointer_width = "64")]
     trait_range_int!(
         usize,
+        try_usize,
         u64,
         u128,
         gen_usize,
@@ -255,6 +499,7 @@ pub trait TurboRand: TurboCore + GenCore {
     #[cfg(target_pointer_width = "16")]
     trait_range_int!(
         isize,
+        try_isize,
         u16,
         u32,
         gen_isize,
@@ -263,6 +508,7 @@ pub trait TurboRand: TurboCore + GenCore {
     #[cfg(target_pointer_width = "32")]
     trait_range_int!(
         isize,
+        try_isize,
         u32,
         u64,
         gen_isize,
@@ -271,6 +517,7 @@ pub trait TurboRand: TurboCore + GenCore {
     #[cfg(target_pointer_width = "64")]
     trait_range_int!(
         isize,
+        try_isize,
         u64,
         u128,
         gen_isize,
@@ -312,21 +559,52 @@ pub trait TurboRand: TurboCore + GenCore {
 
     /// Returns a `usize` value for stable indexing across different
     /// word size platforms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty or invalid.
     #[inline]
     fn index(&self, bound: impl RangeBounds<usize>) -> usize {
+        // Excluded bounds saturate rather than being rejected, so that ranges
+        // such as `..0` keep returning the bound itself.
+        let lower = match bound.start_bound() {
+            Bound::Included(&val) => val,
+            Bound::Excluded(&val) => val.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let upper = match bound.end_bound() {
+            Bound::Included(&val) => val,
+            Bound::Excluded(&val) => val.saturating_sub(1),
+            Bound::Unbounded => usize::MAX,
+        };
+
+        match self.try_index(lower..=upper) {
+            Ok(value) => value,
+            Err(_) => panic!("Range should not be zero sized or invalid"),
+        }
+    }
+
+    /// Returns a `usize` value for stable indexing across different
+    /// word size platforms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRange`] if the range is empty or invalid.
+    #[inline]
+    fn try_index(&self, bound: impl RangeBounds<usize>) -> Result<usize, Error> {
         let lower = match bound.start_bound() {
             Bound::Included(&val) => val as u64,
-            Bound::Excluded(&val) => val.saturating_add(1) as u64,
+            Bound::Excluded(&val) => val.checked_add(1).ok_or(Error::InvalidRange)? as u64,
             Bound::Unbounded => 0,
         };
         let upper = match bound.end_bound() {
             Bound::Included(&val) => val as u64,
-            Bound::Excluded(&val) => val.saturating_sub(1) as u64,
+            Bound::Excluded(&val) => val.checked_sub(1).ok_or(Error::InvalidRange)? as u64,
             Bound::Unbounded => usize::MAX as u64,
         };
         // `bound` will always either be u64 or smaller as `usize`, so it will
         // never overflow on a u64 bound.
-        self.u64(lower..=upper) as usize
+        self.try_u64(lower..=upper).map(|value| value as usize)
     }
 
     /// Returns a random boolean value.
@@ -340,6 +618,7 @@ pub trait TurboRand: TurboCore + GenCore {
     /// assert_eq!(rng.bool(), true);
     /// ```
     #[inline]
+    #[allow(clippy::manual_is_multiple_of)]
     fn bool(&self) -> bool {
         self.gen_u8() % 2 == 0
     }
@@ -362,20 +641,44 @@ pub trait TurboRand: TurboCore + GenCore {
     /// ```
     #[inline]
     fn chance(&self, rate: f64) -> bool {
+        match self.try_chance(rate) {
+            Ok(value) => value,
+            Err(_) => panic!("rate value is not between 0.0 and 1.0, received {rate}"),
+        }
+    }
+
+    /// Returns a boolean value based on a rate. `rate` represents
+    /// the chance to return a `true` value, with `0.0` being no
+    /// chance and `1.0` will always return true.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRate`] if `rate` is *not* a value between 0.0 and 1.0.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::{prelude::*, Error};
+    ///
+    /// let rng = Rng::with_seed(Default::default());
+    ///
+    /// assert_eq!(rng.try_chance(1.0), Ok(true));
+    /// assert_eq!(rng.try_chance(1.5), Err(Error::InvalidRate));
+    /// ```
+    #[inline]
+    fn try_chance(&self, rate: f64) -> Result<bool, Error> {
         const SCALE: f64 = 2.0 * (1u64 << 63) as f64;
 
-        assert!(
-            (0.0..=1.0).contains(&rate),
-            "rate value is not between 0.0 and 1.0, received {rate}",
-        );
+        if !(0.0..=1.0).contains(&rate) {
+            return Err(Error::InvalidRate);
+        }
 
         let rate_int = (rate * SCALE) as u64;
 
-        match rate_int {
+        Ok(match rate_int {
             u64::MAX => true,
             0 => false,
             _ => self.gen_u64() < rate_int,
-        }
+        })
     }
 
     /// Samples a random item from a slice of values.
@@ -636,6 +939,42 @@ pub trait TurboRand: TurboCore + GenCore {
         }
     }
 
+    /// Fallible version of [`TurboRand::weighted_sample`], returning an [`Error`] rather
+    /// than panicking on an invalid `rate` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRate`] if the returned value of the `weight_sampler` closure
+    /// is not between `0.0` and `1.0`.
+    #[inline]
+    fn try_weighted_sample<'a, T, F>(
+        &self,
+        list: &'a [T],
+        weight_sampler: F,
+    ) -> Result<Option<&'a T>, Error>
+    where
+        F: Fn((&T, usize)) -> f64,
+    {
+        // Check how many items are in the list
+        match list.len() {
+            // No values in list, therefore return None.
+            0 => Ok(None),
+            // Only a single value in list, therefore sampling will always yield that value.
+            1 => Ok(list.first()),
+            // Sample the list, and then check if it passes the weighted chance.
+            // Keep repeating until a value succeds and return that.
+            len => loop {
+                let index = self.index(..len);
+
+                if let Some(item) = list.get(index) {
+                    if self.try_chance(weight_sampler((item, index)))? {
+                        return Ok(Some(item));
+                    }
+                }
+            },
+        }
+    }
+
     /// [Stochastic Acceptance](https://arxiv.org/abs/1109.3627) implementation of Roulette Wheel
     /// weighted selection. Uses a closure to return a `rate` value for each randomly sampled item
     /// to decide whether to return it or not. The returned `f64` value must be between `0.0` and `1.0`.
@@ -694,6 +1033,47 @@ pub trait TurboRand: TurboCore + GenCore {
         }
     }
 
+    /// Fallible version of [`TurboRand::weighted_sample_iter`], returning an [`Error`] rather
+    /// than panicking on an invalid `rate` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRate`] if the returned value of the `weight_sampler` closure
+    /// is not between `0.0` and `1.0`.
+    #[inline]
+    fn try_weighted_sample_iter<T, F>(
+        &self,
+        mut list: T,
+        weight_sampler: F,
+    ) -> Result<Option<T::Item>, Error>
+    where
+        T: Iterator + Clone,
+        F: Fn((&T::Item, usize)) -> f64,
+    {
+        let (lower, _) = list.size_hint();
+
+        match lower {
+            0 => Ok(None),
+            1 => Ok(list.next()),
+            _ => {
+                let mut sampler = list.clone();
+
+                loop {
+                    let selected = self.index(..lower);
+
+                    match sampler.by_ref().nth(selected) {
+                        Some(item) if self.try_chance(weight_sampler((&item, selected)))? => {
+                            return Ok(Some(item));
+                        }
+                        _ => {
+                            sampler = list.clone();
+                        }
+                    };
+                }
+            }
+        }
+    }
+
     /// [Stochastic Acceptance](https://arxiv.org/abs/1109.3627) implementation of Roulette Wheel
     /// weighted selection. Uses a closure to return a `rate` value for each randomly sampled item
     /// to decide whether to return it or not. The returned `f64` value must be between `0.0` and `1.0`.
@@ -769,6 +1149,42 @@ pub trait TurboRand: TurboCore + GenCore {
         }
     }
 
+    /// Fallible version of [`TurboRand::weighted_sample_mut`], returning an [`Error`] rather
+    /// than panicking on an invalid `rate` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRate`] if the returned value of the `weight_sampler` closure
+    /// is not between `0.0` and `1.0`.
+    #[inline]
+    fn try_weighted_sample_mut<'a, T, F>(
+        &self,
+        list: &'a mut [T],
+        weight_sampler: F,
+    ) -> Result<Option<&'a mut T>, Error>
+    where
+        F: Fn((&T, usize)) -> f64,
+    {
+        // Check how many items are in the list
+        match list.len() {
+            // No values in list, therefore return None.
+            0 => Ok(None),
+            // Only a single value in list, therefore sampling will always yield that value.
+            1 => Ok(list.first_mut()),
+            // Sample the list, and then check if it passes the weighted chance.
+            // Keep repeating until a value succeds and return that.
+            len => loop {
+                let index = self.index(..len);
+
+                if let Some(item) = list.get(index) {
+                    if self.try_chance(weight_sampler((item, index)))? {
+                        return Ok(list.get_mut(index));
+                    }
+                }
+            },
+        }
+    }
+
     /// Shuffles a slice randomly in O(n) time.
     ///
     /// # Example
@@ -889,18 +1305,33 @@ pub trait TurboRand: TurboCore + GenCore {
     /// Panics if the `radix` is zero or greater than 36.
     #[inline]
     fn digit(&self, radix: u8) -> char {
+        match self.try_digit(radix) {
+            Ok(digit) => digit,
+            Err(_) if radix == 0 => panic!("radix cannot be zero"),
+            Err(_) => panic!("radix cannot be greater than 36"),
+        }
+    }
+
+    /// Generate a random digit in the given `radix`.
+    ///
+    /// Digits are represented by `char`s in ranges 0-9 and a-z.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRadix`] if the `radix` is zero or greater than 36.
+    #[inline]
+    fn try_digit(&self, radix: u8) -> Result<char, Error> {
         match radix {
-            0 => panic!("radix cannot be zero"),
             1..=36 => {
                 let num = self.u8(..radix);
 
-                if num < 10 {
+                Ok(if num < 10 {
                     (b'0' + num) as char
                 } else {
                     (b'a' + num - 10) as char
-                }
+                })
             }
-            _ => panic!("radix cannot be greater than 36"),
+            _ => Err(Error::InvalidRadix),
         }
     }
 
@@ -921,6 +1352,19 @@ pub trait TurboRand: TurboCore + GenCore {
     /// Panics if the range is empty.
     #[inline]
     fn char(&self, bounds: impl RangeBounds<char>) -> char {
+        match self.try_char(bounds) {
+            Ok(character) => character,
+            Err(_) => panic!("Invalid character range"),
+        }
+    }
+
+    /// Generates a random `char` in the given range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRange`] if the range is empty or invalid.
+    #[inline]
+    fn try_char(&self, bounds: impl RangeBounds<char>) -> Result<char, Error> {
         const SURROGATE_START: u32 = 0xd800u32;
         const SURROGATE_LENGTH: u32 = 0x800u32;
 
@@ -933,7 +1377,7 @@ pub trait TurboRand: TurboCore + GenCore {
                 } else {
                     x as u32 + 1
                 };
-                char::from_u32(scalar).expect("Invalid exclusive lower character bound")
+                char::from_u32(scalar).ok_or(Error::InvalidRange)?
             }
         };
 
@@ -944,13 +1388,15 @@ pub trait TurboRand: TurboCore + GenCore {
                 let scalar = if x as u32 == SURROGATE_START + SURROGATE_LENGTH {
                     SURROGATE_START - 1
                 } else {
-                    (x as u32).wrapping_sub(1)
+                    (x as u32).checked_sub(1).ok_or(Error::InvalidRange)?
                 };
-                char::from_u32(scalar).expect("Invalid exclusive upper character bound")
+                char::from_u32(scalar).ok_or(Error::InvalidRange)?
             }
         };
 
-        assert!(upper >= lower, "Invalid character range");
+        if upper < lower {
+            return Err(Error::InvalidRange);
+        }
 
         let lower_scalar = lower as u32;
         let upper_scalar = upper as u32;
@@ -968,7 +1414,7 @@ pub trait TurboRand: TurboCore + GenCore {
             val += gap;
         }
 
-        char::from_u32(val).unwrap()
+        Ok(char::from_u32(val).unwrap())
     }
 
     /// Fills a mutable slice with random values.
@@ -1128,6 +1574,102 @@ pub trait ForkableCore: TurboCore {
     fn fork(&self) -> Self;
 }
 
+/// Trait for deriving named child instances from a [`ForkableCore`] instance,
+/// for hierarchical seeding where each subsystem gets its own generator that
+/// stays the same however the others are added or ordered.
+pub trait LabelledForkCore: ForkableCore {
+    /// Derives a child instance from the current state of `self` and a label,
+    /// without advancing `self`. Unlike [`ForkableCore::fork`], a child does not
+    /// depend on how many labelled children were derived before it, so
+    /// subsystems can be added or reordered while the generators of the others
+    /// stay the same.
+    ///
+    /// The child does depend on the position of `self`, so drawing from `self`
+    /// or forking it with [`ForkableCore::fork`] changes every labelled child
+    /// derived afterwards. For stable children, derive them all from a parent
+    /// that is used for nothing else.
+    ///
+    /// The state and label are mixed through a [`SeedSequence`] to seed the
+    /// child, which is not a cryptographic key derivation. [`SecureCore`]
+    /// generators instead hash their state and the label with SHA-256, so
+    /// that a child reveals nothing of the parent's key.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let world1 = Rng::with_seed(Default::default());
+    /// let world2 = Rng::with_seed(Default::default());
+    ///
+    /// let audio = world1.fork_with_label(b"audio");
+    /// let physics1 = world1.fork_with_label(b"physics");
+    /// let physics2 = world2.fork_with_label(b"physics");
+    ///
+    /// // Deriving other children first makes no difference...
+    /// assert_eq!(physics1, physics2);
+    /// // ...while different labels yield different children.
+    /// assert_ne!(audio, physics1);
+    /// // The parents are not advanced either.
+    /// assert_eq!(world1, world2);
+    ///
+    /// // Drawing from a parent moves it on, and so changes its children.
+    /// world1.bool();
+    ///
+    /// assert_ne!(world1.fork_with_label(b"physics"), physics1);
+    /// ```
+    fn fork_with_label(&self, label: &[u8]) -> Self;
+
+    /// Derives a child instance from the current state of `self` and a string
+    /// label, without advancing `self`. See [`LabelledForkCore::fork_with_label`].
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let world = Rng::with_seed(Default::default());
+    ///
+    /// assert_eq!(world.derive_child("physics"), world.fork_with_label(b"physics"));
+    /// ```
+    #[inline]
+    fn derive_child(&self, label: &str) -> Self
+    where
+        Self: Sized,
+    {
+        self.fork_with_label(label.as_bytes())
+    }
+}
+
+/// Trait for generators that can be split into non-overlapping streams from a
+/// single seed, with any stream being constructed directly from its index in
+/// constant time. Unlike [`ForkableCore::fork`], stream `i` does not depend on
+/// any other stream having been created first, so parallel workers can each
+/// build their own generator from their rank alone.
+///
+/// See [`RngStreams`](crate::RngStreams) for handing out streams from a shared seed.
+pub trait StreamableCore: SeededCore {
+    /// Creates the generator for stream `index` of the given seed. Streams of
+    /// the same seed never overlap as long as each stays within its share of
+    /// the generator's output, as documented by the implementing type.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRange`] if `index` exceeds the number of
+    /// non-overlapping streams the generator can be split into.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let stream0 = Rng::try_with_stream(42, 0).unwrap();
+    /// let stream1 = Rng::try_with_stream(42, 1).unwrap();
+    ///
+    /// assert_ne!(stream0.u64(..), stream1.u64(..));
+    /// ```
+    fn try_with_stream(seed: Self::Seed, index: u64) -> Result<Self, Error>
+    where
+        Self: Sized;
+}
+
 impl<T: TurboCore + GenCore + ?Sized> TurboRand for T {}
 
 #[cfg(feature = "alloc")]
@@ -1148,6 +1690,7 @@ impl<T: GenCore + ?Sized> GenCore for Box<T> {
     }
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a, T: TurboCore + ?Sized> TurboCore for &'a T {
     #[inline(always)]
     fn fill_bytes(&self, buffer: &mut [u8]) {
@@ -1155,6 +1698,7 @@ impl<'a, T: TurboCore + ?Sized> TurboCore for &'a T {
     }
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a, T: GenCore + ?Sized> GenCore for &'a T {
     const GEN_KIND: TurboKind = T::GEN_KIND;
 
@@ -1164,6 +1708,7 @@ impl<'a, T: GenCore + ?Sized> GenCore for &'a T {
     }
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a, T: TurboCore + ?Sized> TurboCore for &'a mut T {
     #[inline(always)]
     fn fill_bytes(&self, buffer: &mut [u8]) {
@@ -1171,6 +1716,7 @@ impl<'a, T: TurboCore + ?Sized> TurboCore for &'a mut T {
     }
 }
 
+#[allow(clippy::needless_lifetimes)]
 impl<'a, T: GenCore + ?Sized> GenCore for &'a mut T {
     const GEN_KIND: TurboKind = T::GEN_KIND;
 
@@ -1267,6 +1813,7 @@ mod tests {
     fn seeded_methods() {
         let rng = TestRng::with_seed(5);
 
+        #[allow(clippy::multiple_bound_locations)]
         fn test_seeded_methods<T: GenCore + SeededCore>(source: &T)
         where
             T: SeededCore<Seed = u8>,
@@ -1348,4 +1895,104 @@ mod tests {
 
         assert_eq!(&buf, &[0x04030201, 0x08070605, 0x0C0B0A09, 0x100F0E0D]);
     }
+
+    #[test]
+    fn seed_bytes_from_source() {
+        let rng = TestRng::new();
+
+        let seed = <[u64; 2]>::from_fill(|buffer| rng.fill_bytes(buffer));
+
+        assert_eq!(seed, [0x0706050403020100, 0x0F0E0D0C0B0A0908]);
+
+        let seed = <[u8; 3]>::from_fill(|buffer| rng.fill_bytes(buffer));
+
+        assert_eq!(seed, [16, 17, 18]);
+    }
+
+    #[test]
+    fn excluded_bounds_saturate() {
+        let rng = TestRng::new();
+
+        assert_eq!(rng.u64(..0), 0);
+        assert_eq!(
+            rng.i32((Bound::Excluded(i32::MAX), Bound::Unbounded)),
+            i32::MAX
+        );
+        assert_eq!(rng.i128(..i128::MIN), i128::MIN);
+        assert_eq!(
+            rng.u128((Bound::Excluded(u128::MAX), Bound::Unbounded)),
+            u128::MAX
+        );
+        assert_eq!(rng.index(..0), 0);
+    }
+
+    #[test]
+    fn try_methods_reject_empty_excluded_bounds() {
+        let rng = TestRng::new();
+
+        assert_eq!(rng.try_u64(..0), Err(Error::InvalidRange));
+        assert_eq!(
+            rng.try_i32((Bound::Excluded(i32::MAX), Bound::Unbounded)),
+            Err(Error::InvalidRange)
+        );
+        assert_eq!(rng.try_i128(..i128::MIN), Err(Error::InvalidRange));
+        assert_eq!(
+            rng.try_u128((Bound::Excluded(u128::MAX), Bound::Unbounded)),
+            Err(Error::InvalidRange)
+        );
+        assert_eq!(rng.try_index(..0), Err(Error::InvalidRange));
+        assert_eq!(
+            rng.try_index((Bound::Excluded(usize::MAX), Bound::Unbounded)),
+            Err(Error::InvalidRange)
+        );
+        assert_eq!(rng.try_index(..1), Ok(0));
+    }
+
+    #[test]
+    fn try_methods_reject_invalid_inputs() {
+        let rng = TestRng::new();
+
+        assert_eq!(rng.try_u8(5..5), Err(Error::InvalidRange));
+        assert_eq!(rng.try_u128(u128::MAX..), Ok(u128::MAX));
+        assert_eq!(
+            rng.try_index((Bound::Excluded(3), Bound::Included(3))),
+            Err(Error::InvalidRange)
+        );
+        assert_eq!(rng.try_chance(-0.5), Err(Error::InvalidRate));
+        assert_eq!(rng.try_digit(37), Err(Error::InvalidRadix));
+        assert_eq!(rng.try_char('b'..'a'), Err(Error::InvalidRange));
+        assert_eq!(
+            rng.try_weighted_sample(&[1, 2], |_| 2.0),
+            Err(Error::InvalidRate)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rate value is not between 0.0 and 1.0, received 2")]
+    fn weighted_sample_reports_rejected_weight() {
+        let rng = TestRng::new();
+
+        rng.weighted_sample(&[1, 2], |_| 2.0);
+    }
+
+    #[cfg(feature = "wyrand")]
+    #[test]
+    fn try_from_failing_entropy_source() {
+        struct FailingSource;
+
+        impl EntropySource for FailingSource {
+            fn fill_entropy(&self, _buffer: &mut [u8]) {
+                panic!("source should not be drawn from infallibly");
+            }
+
+            fn try_fill_entropy(&self, _buffer: &mut [u8]) -> Result<(), Error> {
+                Err(Error::EntropyUnavailable)
+            }
+        }
+
+        assert!(matches!(
+            crate::rng::Rng::try_from_entropy_source(&FailingSource),
+            Err(Error::EntropyUnavailable)
+        ));
+    }
 }
@@ -0,0 +1,345 @@
+//! Fast but **not** cryptographically secure PRNGs based on the
+//! [Xoshiro/Xoroshiro](https://prng.di.unimi.it/) family of generators.
+//!
+//! All generators provide `jump` and `long_jump` methods, which advance
+//! the state by a large, fixed amount of steps. These can be used to split
+//! a single seed into many non-overlapping sequences, such as for parallel
+//! computations.
+use crate::{
+    source::xoshiro::{PlusPlus, StarStar, Xoroshiro128PlusPlus, Xoshiro256},
+    ForkableCore, GenCore, LabelledForkCore, SeedSequence, SeededCore, TurboCore, TurboKind,
+};
+
+#[cfg(feature = "std")]
+use crate::{entropy::LocalRng, Error};
+
+#[cfg(feature = "fmt")]
+use crate::Debug;
+
+#[cfg(feature = "serialize")]
+use crate::{Deserialize, Serialize};
+
+#[inline]
+fn bytes_to_words<const BYTES: usize, const WORDS: usize>(seed: [u8; BYTES]) -> [u64; WORDS] {
+    core::array::from_fn(|index| {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&seed[index * 8..(index + 1) * 8]);
+        u64::from_le_bytes(word)
+    })
+}
+
+macro_rules! xoshiro_rng {
+    (
+        $(#[$meta:meta])*
+        $name:ident,
+        $source:ty,
+        $seed_size:literal,
+        $words:literal,
+        $local:ident,
+        $jump_doc:literal,
+        $long_jump_doc:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "fmt", derive(Debug))]
+        #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+        #[cfg_attr(docsrs, doc(cfg(feature = "xoshiro")))]
+        #[repr(transparent)]
+        pub struct $name($source);
+
+        impl $name {
+            #[doc = $jump_doc]
+            #[inline]
+            pub fn jump(&self) {
+                self.0.jump();
+            }
+
+            #[doc = $long_jump_doc]
+            #[inline]
+            pub fn long_jump(&self) {
+                self.0.long_jump();
+            }
+        }
+
+        #[cfg(feature = "std")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl $name {
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] with a randomised seed.")]
+            #[inline]
+            #[must_use]
+            pub fn new() -> Self {
+                $local.with(|local| local.with(|rng| rng.fork()))
+            }
+
+            #[doc = concat!("Creates a new [`", stringify!($name), "`] with a randomised seed, or returns")]
+            /// [`Error::EntropyUnavailable`] if the thread-local generator could not
+            /// be seeded.
+            #[inline]
+            pub fn try_new() -> Result<Self, Error> {
+                $local.with(|local| local.try_with(|rng| rng.fork()))
+            }
+
+            /// Reseeds the current thread-local generator.
+            #[inline]
+            pub fn reseed_local(seed: [u8; $seed_size]) {
+                $local.with(|local| local.with(|rng| rng.reseed(seed)));
+            }
+        }
+
+        impl TurboCore for $name {
+            #[inline]
+            fn fill_bytes(&self, buffer: &mut [u8]) {
+                self.0.fill(buffer);
+            }
+        }
+
+        impl GenCore for $name {
+            const GEN_KIND: TurboKind = TurboKind::FAST;
+
+            #[inline]
+            fn gen<const SIZE: usize>(&self) -> [u8; SIZE] {
+                self.0.rand()
+            }
+        }
+
+        impl SeededCore for $name {
+            type Seed = [u8; $seed_size];
+
+            /// Creates a new instance with the given seed. An all-zero seed
+            /// is not a valid state for this generator, so it is replaced
+            /// by a fixed, non-zero state instead.
+            #[inline]
+            fn with_seed(seed: Self::Seed) -> Self {
+                Self(<$source>::with_seed(bytes_to_words::<$seed_size, $words>(
+                    seed,
+                )))
+            }
+
+            #[inline]
+            fn reseed(&self, seed: Self::Seed) {
+                self.0.reseed(bytes_to_words::<$seed_size, $words>(seed));
+            }
+        }
+
+        impl ForkableCore for $name {
+            #[inline]
+            fn fork(&self) -> Self {
+                Self::with_seed(self.0.rand())
+            }
+        }
+
+        impl LabelledForkCore for $name {
+            #[inline]
+            fn fork_with_label(&self, label: &[u8]) -> Self {
+                Self::from_seed_sequence(SeedSequence::labelled(self.0.state_words(), label))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl Default for $name {
+            #[doc = concat!("Initialises a default instance of [`", stringify!($name), "`]. Warning, the default is")]
+            /// seeded with a randomly generated state, so this is **not** deterministic.
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        thread_local! {
+            static $local: LocalRng<$name> = const { LocalRng::new(stringify!($local)) };
+        }
+    };
+}
+
+xoshiro_rng!(
+    /// A Random Number generator, powered by the `Xoshiro256++` algorithm.
+    /// Recommended as the general purpose Xoshiro variant.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng = Xoshiro256PlusPlusRng::with_seed([1; 32]);
+    /// let stream = rng.clone();
+    ///
+    /// stream.jump();
+    ///
+    /// assert_ne!(rng.u64(..), stream.u64(..));
+    /// ```
+    Xoshiro256PlusPlusRng,
+    Xoshiro256<PlusPlus>,
+    32,
+    4,
+    XOSHIRO256PP,
+    "Advances the generator by 2^128 steps. Repeated calls yield up to 2^128 non-overlapping sequences.",
+    "Advances the generator by 2^192 steps. Repeated calls yield up to 2^64 starting points, each able to be further split with [`Self::jump`]."
+);
+
+xoshiro_rng!(
+    /// A Random Number generator, powered by the `Xoshiro256**` algorithm.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng = Xoshiro256StarStarRng::with_seed([1; 32]);
+    ///
+    /// let value = rng.u64(..);
+    /// ```
+    Xoshiro256StarStarRng,
+    Xoshiro256<StarStar>,
+    32,
+    4,
+    XOSHIRO256SS,
+    "Advances the generator by 2^128 steps. Repeated calls yield up to 2^128 non-overlapping sequences.",
+    "Advances the generator by 2^192 steps. Repeated calls yield up to 2^64 starting points, each able to be further split with [`Self::jump`]."
+);
+
+xoshiro_rng!(
+    /// A Random Number generator, powered by the `Xoroshiro128++` algorithm.
+    /// Smaller state than the `Xoshiro256` variants, but with a shorter period.
+    ///
+    /// # Example
+    /// ```
+    /// use turborand::prelude::*;
+    ///
+    /// let rng = Xoroshiro128PlusPlusRng::with_seed([1; 16]);
+    ///
+    /// let value = rng.u64(..);
+    /// ```
+    Xoroshiro128PlusPlusRng,
+    Xoroshiro128PlusPlus,
+    16,
+    2,
+    XOROSHIRO128PP,
+    "Advances the generator by 2^64 steps. Repeated calls yield up to 2^64 non-overlapping sequences.",
+    "Advances the generator by 2^96 steps. Repeated calls yield up to 2^32 starting points, each able to be further split with [`Self::jump`]."
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "fmt", feature = "alloc"))]
+    #[test]
+    fn no_leaking_debug() {
+        #[cfg(all(feature = "alloc", not(feature = "std")))]
+        use alloc::format;
+
+        let rng = Xoshiro256PlusPlusRng::with_seed([1; 32]);
+
+        assert_eq!(
+            format!("{rng:?}"),
+            "Xoshiro256PlusPlusRng(Xoshiro256PlusPlus)"
+        );
+
+        let rng = Xoroshiro128PlusPlusRng::with_seed([1; 16]);
+
+        assert_eq!(
+            format!("{rng:?}"),
+            "Xoroshiro128PlusPlusRng(Xoroshiro128PlusPlus)"
+        );
+    }
+
+    #[test]
+    fn seed_bytes_are_little_endian() {
+        let mut seed = [0u8; 32];
+        seed[0] = 1;
+        seed[8] = 2;
+        seed[16] = 3;
+        seed[24] = 4;
+
+        let rng = Xoshiro256PlusPlusRng::with_seed(seed);
+
+        assert_eq!(rng.gen_u64(), 41943041);
+    }
+
+    #[test]
+    fn jumped_streams_diverge() {
+        let rng = Xoroshiro128PlusPlusRng::with_seed([7; 16]);
+        let jumped = rng.clone();
+
+        jumped.jump();
+
+        assert_ne!(rng, jumped);
+
+        let long_jumped = rng.clone();
+
+        long_jumped.long_jump();
+
+        assert_ne!(jumped, long_jumped);
+    }
+
+    #[test]
+    fn forking() {
+        let rng1 = Xoshiro256StarStarRng::with_seed([3; 32]);
+        let rng2 = Xoshiro256StarStarRng::with_seed([3; 32]);
+
+        let forked1 = rng1.fork();
+        let forked2 = rng2.fork();
+
+        assert_ne!(forked1, rng1);
+        assert_eq!(forked1, forked2);
+        assert_eq!(forked1.gen_u64(), forked2.gen_u64());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_tokens() {
+        use serde_test::{assert_tokens, Token};
+
+        let mut seed = [0u8; 16];
+        seed[0] = 1;
+        seed[8] = 2;
+
+        let rng = Xoroshiro128PlusPlusRng::with_seed(seed);
+
+        assert_tokens(
+            &rng,
+            &[
+                Token::NewtypeStruct {
+                    name: "Xoroshiro128PlusPlusRng",
+                },
+                Token::Struct {
+                    name: "Xoroshiro128PlusPlus",
+                    len: 1,
+                },
+                Token::BorrowedStr("state"),
+                Token::Tuple { len: 2 },
+                Token::U64(1),
+                Token::U64(2),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serde_rejects_zero_state() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<Xoshiro256StarStarRng>(
+            &[
+                Token::NewtypeStruct {
+                    name: "Xoshiro256StarStarRng",
+                },
+                Token::Struct {
+                    name: "Xoshiro256",
+                    len: 1,
+                },
+                Token::BorrowedStr("state"),
+                Token::Tuple { len: 4 },
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::U64(0),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+            "invalid value: an all-zero state, expected a non-zero state",
+        );
+    }
+}
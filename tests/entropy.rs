@@ -0,0 +1,68 @@
+//! Kept apart from the main suite, as the global entropy source seeds the
+//! thread-locals of every thread of the process, including those running
+//! other tests.
+#![cfg(all(feature = "std", feature = "wyrand"))]
+
+use std::{
+    sync::{Mutex, PoisonError},
+    thread,
+};
+
+use turborand::{entropy, prelude::*};
+
+/// Test double yielding the byte offsets as entropy, so that draws are
+/// deterministic regardless of how many are made.
+struct OffsetSource;
+
+impl EntropySource for OffsetSource {
+    fn fill_entropy(&self, buffer: &mut [u8]) {
+        buffer
+            .iter_mut()
+            .enumerate()
+            .for_each(|(offset, byte)| *byte = offset as u8);
+    }
+}
+
+/// Test double that unregisters itself when sampled, so that only the first
+/// thread-local to be seeded draws from it.
+struct OneShotSource;
+
+impl EntropySource for OneShotSource {
+    fn fill_entropy(&self, buffer: &mut [u8]) {
+        entropy::reset_global_source();
+        OffsetSource.fill_entropy(buffer);
+    }
+}
+
+/// Serialises the tests, as they all register their own global source.
+static GLOBAL: Mutex<()> = Mutex::new(());
+
+fn spawn_new() -> u64 {
+    thread::spawn(|| Rng::new().gen_u64()).join().unwrap()
+}
+
+#[test]
+fn global_source_seeds_new_thread_locals() {
+    let _guard = GLOBAL.lock().unwrap_or_else(PoisonError::into_inner);
+    let expected = Rng::from_entropy_source(&OffsetSource).fork().gen_u64();
+
+    entropy::set_global_source(OffsetSource);
+
+    assert_eq!(spawn_new(), expected);
+    assert_eq!(spawn_new(), expected);
+
+    entropy::reset_global_source();
+
+    assert_ne!(spawn_new(), expected);
+}
+
+#[test]
+fn global_source_can_reset_itself() {
+    let _guard = GLOBAL.lock().unwrap_or_else(PoisonError::into_inner);
+    let expected = Rng::from_entropy_source(&OffsetSource).fork().gen_u64();
+
+    entropy::set_global_source(OneShotSource);
+
+    assert_eq!(spawn_new(), expected);
+    assert_ne!(spawn_new(), expected);
+}
@@ -0,0 +1,63 @@
+//! Kept apart from the main suite, as forking while other threads hold locks
+//! can deadlock the child. With a single test in this binary, only the
+//! harness's idle main thread runs alongside it, and the child only touches
+//! its own thread-locals before exiting.
+#![cfg(all(unix, not(miri), feature = "std", feature = "wyrand"))]
+
+use turborand::prelude::*;
+
+/// Runs `f` in a forked child process, returning the value it computed.
+fn in_child(f: impl FnOnce() -> u64) -> u64 {
+    let mut fds = [0; 2];
+
+    // SAFETY: `fds` has room for the two file descriptors written by `pipe`,
+    // and the child only computes a value, writes it and exits.
+    unsafe {
+        assert_eq!(libc::pipe(fds.as_mut_ptr()), 0);
+
+        match libc::fork() {
+            -1 => panic!("fork should succeed"),
+            0 => {
+                let value = f().to_le_bytes();
+
+                libc::write(fds[1], value.as_ptr().cast(), value.len());
+                libc::_exit(0);
+            }
+            child => {
+                let mut value = [0u8; 8];
+                let mut status = 0;
+
+                assert_eq!(
+                    libc::read(fds[0], value.as_mut_ptr().cast(), value.len()),
+                    8
+                );
+                assert_eq!(libc::waitpid(child, &mut status, 0), child);
+
+                libc::close(fds[0]);
+                libc::close(fds[1]);
+
+                u64::from_le_bytes(value)
+            }
+        }
+    }
+}
+
+#[test]
+fn forked_child_reseeds_thread_locals() {
+    // Seed the thread-local generators before forking.
+    Rng::new().gen_u64();
+
+    #[cfg(feature = "chacha")]
+    ChaChaRng::new().gen_u64();
+
+    let child = in_child(|| Rng::new().gen_u64());
+
+    assert_ne!(child, Rng::new().gen_u64());
+
+    #[cfg(feature = "chacha")]
+    {
+        let child = in_child(|| ChaChaRng::new().gen_u64());
+
+        assert_ne!(child, ChaChaRng::new().gen_u64());
+    }
+}
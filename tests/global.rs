@@ -0,0 +1,47 @@
+//! Kept apart from the main suite, as the global seed affects every thread of
+//! the process, including those running other tests.
+#![cfg(all(feature = "std", feature = "wyrand"))]
+
+use std::thread;
+
+use turborand::{global, prelude::*};
+
+fn spawn_named(name: &str) -> u64 {
+    thread::Builder::new()
+        .name(name.into())
+        .spawn(|| Rng::new().gen_u64())
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+#[test]
+fn global_seed_makes_thread_locals_deterministic() {
+    std::env::set_var("TURBORAND_SEED", "1234");
+
+    assert_eq!(global::seed(), Some(1234));
+
+    let main = Rng::new().gen_u64();
+    let first = spawn_named("worker");
+    let second = spawn_named("worker");
+
+    assert_ne!(first, second);
+    assert_ne!(main, first);
+
+    // Setting the seed reseeds generators seeded before, deterministically.
+    global::set_seed(5678);
+
+    let reseeded = Rng::new().gen_u64();
+
+    assert_eq!(global::seed(), Some(5678));
+    assert_ne!(reseeded, main);
+
+    global::set_seed(5678);
+
+    assert_eq!(Rng::new().gen_u64(), reseeded);
+
+    global::reset_seed();
+
+    assert_eq!(global::seed(), None);
+    assert_ne!(Rng::new().gen_u64(), Rng::new().gen_u64());
+}
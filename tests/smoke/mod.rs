@@ -631,6 +631,35 @@ fn chacha_rng_spread_test() {
     );
 }
 
+#[cfg(feature = "xoshiro")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn xoshiro_rng_smoke_test() {
+    let rand = Xoshiro256PlusPlusRng::with_seed([0u8; 32]);
+
+    let value = rand.u64(5..=10);
+
+    assert_eq!(&value, &6);
+
+    for _ in 0..128 {
+        let value = rand.u64(2..=20);
+
+        assert!((2..=20).contains(&value));
+    }
+
+    let rand = Xoroshiro128PlusPlusRng::with_seed([0u8; 16]);
+
+    let value = rand.i64(-5..=5);
+
+    assert_eq!(&value, &-1);
+
+    for _ in 0..128 {
+        let value = rand.i64(-8..=8);
+
+        assert!((-8..=8).contains(&value));
+    }
+}
+
 #[test]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
 fn sample_spread_testing() {